@@ -1,4 +1,4 @@
-use luna_lang::{Context, evaluate, parse_str};
+use luna_lang::{Context, evaluate, parse_str_with_context};
 use rustyline::error::ReadlineError;
 use rustyline::{DefaultEditor, Result as RLResult};
 
@@ -14,13 +14,21 @@ impl Session {
     }
 
     fn process_input(&mut self, input: &str) -> Result<(), String> {
-        let result = parse_str(input);
+        let result = parse_str_with_context(input, &self.context);
 
         match result {
             Ok(expr) => {
                 println!();
                 let result = evaluate(expr, &mut self.context);
-                println!("{}", result);
+
+                for message in self.context.take_messages() {
+                    println!("{}", message);
+                }
+
+                match result {
+                    Ok(result) => println!("{}", result),
+                    Err(error) => println!("Error: {}", error),
+                }
                 println!();
                 Ok(())
             }
@@ -2,5 +2,99 @@ pub use rug::Float as BigFloat;
 pub use rug::float::OrdFloat as OrdBigFloat;
 
 pub use rug::Integer as BigInteger;
+pub use rug::Rational as BigRational;
 
 pub use ustr::Ustr as IString;
+
+/// Returns the number of bits of precision of a real.
+pub fn real_precision(value: &OrdBigFloat) -> u32 {
+    value.as_float().prec()
+}
+
+/// The largest number of decimal digits of precision a real may be given, whether by a precision
+/// annotation such as `` 1.5`20 `` or by `N`. This also limits the exponent of an exact number
+/// written as `1*^n`. Larger values are rejected rather than spending a long time computing with
+/// enormous numbers.
+pub const MAX_PRECISION_DIGITS: u32 = 100_000;
+
+/// Returns the number of bits of precision required to represent the given number of decimal
+/// digits.
+pub fn digits_to_precision(digits: u32) -> u32 {
+    (digits as f64 * std::f64::consts::LOG2_10).ceil() as u32
+}
+
+/// Serializes arbitrary precision numbers as strings, so that no precision is lost to the
+/// limited range of the serialization format's native numbers.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_numbers {
+    use super::{BigFloat, BigInteger, BigRational, OrdBigFloat};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) mod integer {
+        use super::*;
+
+        pub(crate) fn serialize<S: Serializer>(
+            value: &BigInteger,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&value.to_string())
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<BigInteger, D::Error> {
+            let value = String::deserialize(deserializer)?;
+            BigInteger::from_str_radix(&value, 10).map_err(D::Error::custom)
+        }
+    }
+
+    pub(crate) mod rational {
+        use super::*;
+
+        pub(crate) fn serialize<S: Serializer>(
+            value: &BigRational,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&value.to_string())
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<BigRational, D::Error> {
+            let value = String::deserialize(deserializer)?;
+            BigRational::from_str_radix(&value, 10).map_err(D::Error::custom)
+        }
+    }
+
+    /// Reals are serialized with their precision, as the digits alone do not determine it.
+    pub(crate) mod real {
+        use super::*;
+
+        #[derive(Serialize, Deserialize)]
+        struct SerializedReal {
+            value: String,
+            precision: u32,
+        }
+
+        pub(crate) fn serialize<S: Serializer>(
+            value: &OrdBigFloat,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            SerializedReal {
+                value: value.as_float().to_string_radix(10, None),
+                precision: value.as_float().prec(),
+            }
+            .serialize(serializer)
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<OrdBigFloat, D::Error> {
+            let real = SerializedReal::deserialize(deserializer)?;
+            let value = BigFloat::parse(&real.value).map_err(D::Error::custom)?;
+
+            Ok(OrdBigFloat::from(BigFloat::with_val(real.precision, value)))
+        }
+    }
+}
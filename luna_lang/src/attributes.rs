@@ -43,6 +43,63 @@ pub enum Attribute {
     /// When applying the symbol any Sequence objects appearing as arguments should not
     /// automatically be flattened out.
     HoldSequences,
+
+    /// Application of the symbol is automatically threaded over lists.
+    ///
+    /// Lists appearing as arguments must all be of the same length, and any non-list arguments
+    /// are repeated for each application.
+    /// For example `f[{a, b}, {c, d}, x]` will be evaluated into `{f[a, c, x], f[b, d, x]}`.
+    Listable,
+
+    /// Application of the symbol to a single argument is equivalent to the argument itself.
+    ///
+    /// During evaluation single argument applications will be collapsed.
+    /// For example `f[x]` will be evaluated into `x`.
+    OneIdentity,
+}
+
+impl Attribute {
+    /// Every attribute, in declaration order.
+    pub const ALL: [Attribute; 11] = [
+        Attribute::ReadOnly,
+        Attribute::AttributesReadOnly,
+        Attribute::Commutative,
+        Attribute::Associative,
+        Attribute::HoldFirst,
+        Attribute::HoldRest,
+        Attribute::HoldAll,
+        Attribute::HoldAllComplete,
+        Attribute::HoldSequences,
+        Attribute::Listable,
+        Attribute::OneIdentity,
+    ];
+
+    /// Returns the name of the symbol which represents the attribute within the language.
+    ///
+    /// These follow the conventional names, so `Commutative` is named `Orderless` and
+    /// `Associative` is named `Flat`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Attribute::ReadOnly => "Protected",
+            Attribute::AttributesReadOnly => "Locked",
+            Attribute::Commutative => "Orderless",
+            Attribute::Associative => "Flat",
+            Attribute::HoldFirst => "HoldFirst",
+            Attribute::HoldRest => "HoldRest",
+            Attribute::HoldAll => "HoldAll",
+            Attribute::HoldAllComplete => "HoldAllComplete",
+            Attribute::HoldSequences => "SequenceHold",
+            Attribute::Listable => "Listable",
+            Attribute::OneIdentity => "OneIdentity",
+        }
+    }
+
+    /// Returns the attribute represented by the given symbol name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|attribute| attribute.name() == name)
+    }
 }
 
 impl Add<Attribute> for Attribute {
@@ -77,6 +134,14 @@ impl Attributes {
         self.0 |= attributes.0;
     }
 
+    pub fn unset(&mut self, attribute: Attribute) {
+        self.0 &= !(1 << attribute as u32)
+    }
+
+    pub fn unset_all(&mut self, attributes: Attributes) {
+        self.0 &= !attributes.0;
+    }
+
     pub fn read_only(&self) -> bool {
         self.has(Attribute::ReadOnly)
     }
@@ -112,6 +177,21 @@ impl Attributes {
     pub fn hold_sequences(&self) -> bool {
         self.has(Attribute::HoldSequences)
     }
+
+    pub fn listable(&self) -> bool {
+        self.has(Attribute::Listable)
+    }
+
+    pub fn one_identity(&self) -> bool {
+        self.has(Attribute::OneIdentity)
+    }
+
+    /// Iterates over the attributes within the set, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = Attribute> + '_ {
+        Attribute::ALL
+            .into_iter()
+            .filter(|attribute| self.has(*attribute))
+    }
 }
 
 impl Default for Attributes {
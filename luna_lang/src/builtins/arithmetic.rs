@@ -0,0 +1,598 @@
+use super::comparison::compare_numeric;
+use super::exact_number;
+use crate::{
+    Attribute, BigFloat, BigInteger, BigRational, Context, EvalResult, Expr, ExprKind,
+    MAX_PRECISION_DIGITS, Normal, Symbol, SymbolValue, digits_to_precision,
+};
+use crate::{ValueType, parse, sym, try_sequence};
+use std::cmp::Ordering;
+
+/// Registers the `Abs` builtin symbol.
+///
+/// - `Attributes[Abs] = { ReadOnly, AttributesReadOnly }`
+/// - `Abs[x_] := built-in`
+pub(crate) fn register_abs_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Abs),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Abs[x_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let x = &arguments[&Symbol::new("x")];
+
+                    match x.kind() {
+                        ExprKind::Integer(n) => EvalResult::Changed(Expr::from(n.clone().abs())),
+                        ExprKind::Rational(q) => EvalResult::Changed(Expr::from(q.clone().abs())),
+                        ExprKind::Real(r) => {
+                            EvalResult::Changed(Expr::from(r.as_float().clone().abs()))
+                        }
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Abs),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Sqrt` builtin symbol.
+///
+/// - `Attributes[Sqrt] = { ReadOnly, AttributesReadOnly }`
+/// - `Sqrt[x_] := Power[x, 1/2]`
+pub(crate) fn register_sqrt_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Sqrt),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Sqrt[x_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let x = &arguments[&Symbol::new("x")];
+
+                    EvalResult::Changed(Expr::from(Normal::new(
+                        sym!(Power),
+                        vec![x.clone(), Expr::from(BigRational::from((1, 2)))],
+                    )))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Sqrt),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Min` builtin symbol.
+///
+/// - `Attributes[Min] = { ReadOnly, AttributesReadOnly }`
+/// - `Min[exprs___] := built-in`
+pub(crate) fn register_min_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Min),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Min[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    extremum(
+                        &arguments[&Symbol::new("exprs")],
+                        expr,
+                        sym!(Min),
+                        Ordering::Less,
+                    )
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Min),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Max` builtin symbol.
+///
+/// - `Attributes[Max] = { ReadOnly, AttributesReadOnly }`
+/// - `Max[exprs___] := built-in`
+pub(crate) fn register_max_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Max),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Max[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    extremum(
+                        &arguments[&Symbol::new("exprs")],
+                        expr,
+                        sym!(Max),
+                        Ordering::Greater,
+                    )
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Max),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Mod` builtin symbol.
+///
+/// - `Attributes[Mod] = { ReadOnly, AttributesReadOnly }`
+/// - `Mod[a_, b_] := built-in`
+pub(crate) fn register_mod_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Mod),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Mod[a_, b_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let a = &arguments[&Symbol::new("a")];
+                    let b = &arguments[&Symbol::new("b")];
+
+                    match (a.try_integer(), b.try_integer()) {
+                        (Some(a), Some(b)) if !b.is_zero() => {
+                            let (_, remainder) = a.clone().div_rem_floor(b.clone());
+
+                            EvalResult::Changed(Expr::from(remainder))
+                        }
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Mod),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Quotient` builtin symbol.
+///
+/// - `Attributes[Quotient] = { ReadOnly, AttributesReadOnly }`
+/// - `Quotient[a_, b_] := built-in`
+pub(crate) fn register_quotient_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Quotient),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Quotient[a_, b_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let a = &arguments[&Symbol::new("a")];
+                    let b = &arguments[&Symbol::new("b")];
+
+                    match (a.try_integer(), b.try_integer()) {
+                        (Some(a), Some(b)) if !b.is_zero() => {
+                            let (quotient, _) = a.clone().div_rem_floor(b.clone());
+
+                            EvalResult::Changed(Expr::from(quotient))
+                        }
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Quotient),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `GCD` builtin symbol.
+///
+/// - `Attributes[GCD] = { ReadOnly, AttributesReadOnly }`
+/// - `GCD[exprs___] := built-in`
+pub(crate) fn register_gcd_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(GCD),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("GCD[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    fold_integers(
+                        &arguments[&Symbol::new("exprs")],
+                        expr,
+                        sym!(GCD),
+                        BigInteger::new(),
+                        |acc, n| acc.gcd(n),
+                    )
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(GCD),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `LCM` builtin symbol.
+///
+/// - `Attributes[LCM] = { ReadOnly, AttributesReadOnly }`
+/// - `LCM[exprs___] := built-in`
+pub(crate) fn register_lcm_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(LCM),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("LCM[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    fold_integers(
+                        &arguments[&Symbol::new("exprs")],
+                        expr,
+                        sym!(LCM),
+                        BigInteger::ONE.clone(),
+                        |acc, n| acc.lcm(n),
+                    )
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(LCM),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Rational` builtin symbol.
+///
+/// - `Attributes[Rational] = { ReadOnly, AttributesReadOnly }`
+/// - `Rational[n_Integer, d_Integer] := built-in`
+pub(crate) fn register_rational_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Rational),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Rational[n_Integer, d_Integer]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let n = arguments[&Symbol::new("n")].try_integer().unwrap();
+                    let d = arguments[&Symbol::new("d")].try_integer().unwrap();
+
+                    if d.is_zero() {
+                        return EvalResult::Unchanged(expr);
+                    }
+
+                    EvalResult::Changed(exact_number(BigRational::from((n, d))))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Rational),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `N` builtin symbol.
+///
+/// - `Attributes[N] = { ReadOnly, AttributesReadOnly }`
+/// - `N[expr_] := built-in`
+/// - `N[expr_, digits_Integer] := built-in`
+pub(crate) fn register_n_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(N),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("N[expr_]"),
+                condition: None,
+                built_in: |arguments, _, context| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    EvalResult::Changed(numericize(expr, context.default_real_precision()))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_value(
+            &sym!(N),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("N[expr_, digits_Integer]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let digits = arguments[&Symbol::new("digits")].try_integer().unwrap();
+
+                    match digits.to_u32() {
+                        Some(digits) if (1..=MAX_PRECISION_DIGITS).contains(&digits) => {
+                            EvalResult::Changed(numericize(
+                                &arguments[&Symbol::new("expr")],
+                                digits_to_precision(digits),
+                            ))
+                        }
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(N),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Converts every exact number within `expr` into a real with the given precision.
+fn numericize(expr: &Expr, precision: u32) -> Expr {
+    match expr.kind() {
+        ExprKind::Integer(n) => Expr::from(BigFloat::with_val(precision, n)),
+        ExprKind::Rational(q) => Expr::from(BigFloat::with_val(precision, q)),
+        ExprKind::Normal(normal) => Expr::from(Normal::new(
+            normal.head().clone(),
+            normal
+                .elements()
+                .iter()
+                .map(|element| numericize(element, precision))
+                .collect::<Vec<_>>(),
+        )),
+        _ => expr.clone(),
+    }
+}
+
+/// Folds the numeric elements of the `exprs` sequence down to the single element which compares
+/// as `preferred` against all others. Non-numeric elements are kept in a residual `head[...]`.
+fn extremum(exprs: &Expr, expr: Expr, head: Symbol, preferred: Ordering) -> EvalResult {
+    let expr_elements = try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+    let mut best: Option<&Expr> = None;
+    let mut new_elements = Vec::with_capacity(expr_elements.len());
+
+    for element in expr_elements {
+        match element.kind() {
+            ExprKind::Integer(_) | ExprKind::Rational(_) | ExprKind::Real(_) => match best {
+                Some(current) if compare_numeric(element, current) != Some(preferred) => {}
+                _ => best = Some(element),
+            },
+            _ => new_elements.push(element.clone()),
+        }
+    }
+
+    if let Some(best) = best {
+        new_elements.push(best.clone());
+    }
+
+    if new_elements.len() == 1 {
+        EvalResult::Changed(new_elements[0].clone())
+    } else if new_elements != expr_elements {
+        EvalResult::Changed(Expr::from(Normal::new(head, new_elements)))
+    } else {
+        EvalResult::Unchanged(expr)
+    }
+}
+
+/// Folds the integer elements of the `exprs` sequence together using `op`, starting from
+/// `identity`. Non-integer elements are kept in a residual `head[...]`.
+fn fold_integers(
+    exprs: &Expr,
+    expr: Expr,
+    head: Symbol,
+    identity: BigInteger,
+    op: fn(BigInteger, &BigInteger) -> BigInteger,
+) -> EvalResult {
+    let expr_elements = try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+    let mut accumulator: Option<BigInteger> = None;
+    let mut new_elements = Vec::with_capacity(expr_elements.len());
+
+    for element in expr_elements {
+        match element.try_integer() {
+            Some(n) => {
+                accumulator = Some(op(accumulator.unwrap_or_else(|| identity.clone()), n));
+            }
+            None => new_elements.push(element.clone()),
+        }
+    }
+
+    if new_elements.is_empty() {
+        return EvalResult::Changed(Expr::from(accumulator.unwrap_or(identity)));
+    }
+
+    if let Some(accumulator) = accumulator {
+        new_elements.push(Expr::from(accumulator));
+    }
+
+    if new_elements != expr_elements {
+        EvalResult::Changed(Expr::from(Normal::new(head, new_elements)))
+    } else {
+        EvalResult::Unchanged(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod rational {
+        use crate::{BigRational, Expr};
+
+        evaluate_test!(reduced, "SameQ[Rational[6, 4], Rational[3, 2]]", "True");
+        evaluate_test!(integral, "Rational[4, 2]", "2");
+        evaluate_test!(head, "Head[Rational[1, 2]]", "Rational");
+        evaluate_test!(zero_denominator, "Rational[1, 0]", "Rational[1, 0]");
+        evaluate_test!(symbolic, "Rational[x, 2]", "Rational[x, 2]");
+        evaluate_test!(number_q, "NumberQ[Rational[1, 2]]", "True");
+        evaluate_test!(integer_q, "IntegerQ[Rational[1, 2]]", "False");
+        evaluate_test!(abs, "SameQ[Abs[Rational[-1, 2]], Rational[1, 2]]", "True");
+        evaluate_test!(less, "Less[Rational[1, 3], Rational[1, 2], 1]", "True");
+        evaluate_test!(greater_real, "Rational[1, 2] > 0.25", "True");
+        evaluate_test!(equal_real, "Rational[1, 2] == 0.5", "True");
+        evaluate_test!(min, "SameQ[Min[1, Rational[1, 2]], Rational[1, 2]]", "True");
+        evaluate_test!(n, "N[Rational[1, 4]]", "0.25");
+
+        #[test]
+        fn display() {
+            assert_eq!(Expr::from(BigRational::from((3, 2))).to_string(), "3/2");
+            assert_eq!(Expr::from(BigRational::from((-1, 4))).to_string(), "-1/4");
+        }
+    }
+
+    mod n {
+        use crate::{Context, evaluate, parse_str};
+
+        evaluate_test!(integer, "N[2]", "2.");
+        evaluate_test!(list, "N[{1, 2}]", "{1., 2.}");
+        evaluate_test!(real, "N[1.5]", "1.5");
+        evaluate_test!(symbolic, "N[x]", "x");
+        evaluate_test!(nested, "N[f[1, g[2]]]", "f[1., g[2.]]");
+        evaluate_test!(folds, "N[2 + x]", "2. + x");
+        evaluate_test!(digits, "N[2, 30]", "2.");
+        evaluate_test!(invalid_digits, "N[2, 0]", "N[2, 0]");
+        evaluate_test!(too_many_digits, "N[x, 100000000]", "N[x, 100000000]");
+
+        #[test]
+        fn precision() {
+            let mut context = Context::new_global_context();
+
+            let result = evaluate(parse_str("N[2, 30]").unwrap(), &mut context).unwrap();
+
+            assert_eq!(result.try_real().unwrap().as_float().prec(), 100);
+        }
+    }
+
+    mod abs {
+        evaluate_test!(negative_integer, "Abs[-5]", "5");
+        evaluate_test!(positive_integer, "Abs[5]", "5");
+        evaluate_test!(real, "Abs[2.5]", "2.5");
+        evaluate_test!(symbolic, "Abs[x]", "Abs[x]");
+    }
+
+    mod sqrt {
+        use crate::{Context, evaluate, parse_str, real_precision};
+
+        fn precision_of(input: &str) -> u32 {
+            let mut context = Context::new_global_context();
+            let result = evaluate(parse_str(input).unwrap(), &mut context).unwrap();
+
+            real_precision(result.try_real().unwrap())
+        }
+
+        #[test]
+        fn precise_real() {
+            assert_eq!(precision_of("Sqrt[N[2, 50]]"), 167);
+        }
+
+        #[test]
+        fn mixed_precision_reals() {
+            assert_eq!(precision_of("Sqrt[Times[N[2, 50], 1.5]]"), 53);
+            assert_eq!(precision_of("Sqrt[Plus[N[2, 50], N[1, 30]]]"), 100);
+        }
+
+        evaluate_test!(perfect_square, "Sqrt[9]", "3");
+        evaluate_test!(zero, "Sqrt[0]", "0");
+        evaluate_test!(one, "Sqrt[1]", "1");
+        evaluate_test!(
+            large_perfect_square,
+            "Sqrt[152415787532388367501905199875019052100]",
+            "12345678901234567890"
+        );
+        evaluate_test!(perfect_square_rational, "SameQ[Sqrt[4/9], 2/3]", "True");
+        evaluate_test!(non_square, "SameQ[Sqrt[2], Power[2, 1/2]]", "True");
+        evaluate_test!(real, "Sqrt[2.25]", "1.5");
+        evaluate_test!(symbolic, "SameQ[Sqrt[x], Power[x, 1/2]]", "True");
+        evaluate_test!(negative_one, "Sqrt[-1]", "Complex[0, 1]");
+        evaluate_test!(negative_perfect_square, "Sqrt[-9]", "Complex[0, 3]");
+        evaluate_test!(negative_non_square, "SameQ[Sqrt[-2], I * Sqrt[2]]", "True");
+        evaluate_test!(negative_real, "SameQ[Sqrt[-2.0], Power[-2.0, 1/2]]", "True");
+        evaluate_test!(cube_root, "Power[27, 1/3]", "3");
+        evaluate_test!(reciprocal_root, "SameQ[Power[4, -1/2], 1/2]", "True");
+    }
+
+    mod min {
+        evaluate_test!(integers, "Min[3, 1, 2]", "1");
+        evaluate_test!(mixed, "Min[1.5, 2]", "1.5");
+        evaluate_test!(symbolic, "Min[x, 1, 2]", "Min[x, 1]");
+        evaluate_test!(only_symbolic, "Min[x, y]", "Min[x, y]");
+        evaluate_test!(single_argument, "Min[x]", "x");
+    }
+
+    mod max {
+        evaluate_test!(integers, "Max[3, 1, 2]", "3");
+        evaluate_test!(mixed, "Max[1.5, 2]", "2");
+        evaluate_test!(symbolic, "Max[x, 1, 2]", "Max[x, 2]");
+    }
+
+    mod r#mod {
+        evaluate_test!(positive, "Mod[7, 3]", "1");
+        evaluate_test!(negative_dividend, "Mod[-1, 3]", "2");
+        evaluate_test!(negative_divisor, "Mod[7, -3]", "-2");
+        evaluate_test!(divide_by_zero, "Mod[7, 0]", "Mod[7, 0]");
+        evaluate_test!(real, "Mod[7.5, 2]", "Mod[7.5, 2]");
+        evaluate_test!(symbolic, "Mod[x, 3]", "Mod[x, 3]");
+    }
+
+    mod quotient {
+        evaluate_test!(positive, "Quotient[7, 3]", "2");
+        evaluate_test!(negative_dividend, "Quotient[-1, 3]", "-1");
+        evaluate_test!(negative_divisor, "Quotient[7, -3]", "-3");
+        evaluate_test!(divide_by_zero, "Quotient[7, 0]", "Quotient[7, 0]");
+        evaluate_test!(symbolic, "Quotient[x, 3]", "Quotient[x, 3]");
+    }
+
+    mod gcd {
+        evaluate_test!(integers, "GCD[12, 18]", "6");
+        evaluate_test!(variadic, "GCD[12, 18, 8]", "2");
+        evaluate_test!(negative, "GCD[-12, 18]", "6");
+        evaluate_test!(no_arguments, "GCD[]", "0");
+        evaluate_test!(symbolic, "GCD[x, 12, 18]", "GCD[x, 6]");
+    }
+
+    mod lcm {
+        evaluate_test!(integers, "LCM[4, 6]", "12");
+        evaluate_test!(variadic, "LCM[4, 6, 5]", "60");
+        evaluate_test!(no_arguments, "LCM[]", "1");
+        evaluate_test!(symbolic, "LCM[x, 4, 6]", "LCM[x, 12]");
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{Attribute, Context, EvalResult, Expr, Normal, Symbol, SymbolValue, ValueType};
+use crate::{parse, sym};
+
+/// Registers the `Association` builtin symbol, which looks up the value of a key when applied to
+/// it, for example `<|a -> 1|>[a]` is `1`.
+///
+/// - `Attributes[Association] = { ReadOnly, AttributesReadOnly }`
+/// - `Association[rules___][key_] := built-in`
+pub(crate) fn register_association_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Association),
+            ValueType::SubValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Association[rules___][key_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let key = &arguments[&Symbol::new("key")];
+
+                    match lookup(&expr.head(), key) {
+                        Some(value) => EvalResult::Changed(value.unwrap_or_else(|| missing(key))),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Association),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Lookup` builtin symbol, which gives the value of a key in an association, or a
+/// default if the key is absent.
+///
+/// - `Attributes[Lookup] = { ReadOnly, AttributesReadOnly }`
+/// - `Lookup[assoc_, key_] := built-in`
+/// - `Lookup[assoc_, key_, default_] := built-in`
+pub(crate) fn register_lookup_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Lookup),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Lookup[assoc_, key_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let assoc = &arguments[&Symbol::new("assoc")];
+                    let key = &arguments[&Symbol::new("key")];
+
+                    match lookup(assoc, key) {
+                        Some(value) => EvalResult::Changed(value.unwrap_or_else(|| missing(key))),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_value(
+            &sym!(Lookup),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Lookup[assoc_, key_, default_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let assoc = &arguments[&Symbol::new("assoc")];
+                    let key = &arguments[&Symbol::new("key")];
+                    let default = &arguments[&Symbol::new("default")];
+
+                    match lookup(assoc, key) {
+                        Some(value) => {
+                            EvalResult::Changed(value.unwrap_or_else(|| default.clone()))
+                        }
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Lookup),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Keys` builtin symbol, which lists the keys of an association in order.
+///
+/// - `Attributes[Keys] = { ReadOnly, AttributesReadOnly }`
+/// - `Keys[assoc_] := built-in`
+pub(crate) fn register_keys_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Keys),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Keys[assoc_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let assoc = &arguments[&Symbol::new("assoc")];
+
+                    match association_entries(assoc) {
+                        Some(entries) => EvalResult::Changed(Expr::from(Normal::new(
+                            sym!(List),
+                            entries
+                                .into_iter()
+                                .map(|(key, _)| key.clone())
+                                .collect::<Vec<_>>(),
+                        ))),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Keys),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Values` builtin symbol, which lists the values of an association in order.
+///
+/// - `Attributes[Values] = { ReadOnly, AttributesReadOnly }`
+/// - `Values[assoc_] := built-in`
+pub(crate) fn register_values_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Values),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Values[assoc_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let assoc = &arguments[&Symbol::new("assoc")];
+
+                    match association_entries(assoc) {
+                        Some(entries) => EvalResult::Changed(Expr::from(Normal::new(
+                            sym!(List),
+                            entries
+                                .into_iter()
+                                .map(|(_, value)| value.clone())
+                                .collect::<Vec<_>>(),
+                        ))),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Values),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Splits an `Association` into the key and value of each of its `Rule` or `RuleDelayed` entries.
+/// Returns `None` if the expression is not an association, or has an entry which is not a rule.
+pub(crate) fn association_entries(assoc: &Expr) -> Option<Vec<(&Expr, &Expr)>> {
+    assoc
+        .try_normal_head(&sym!(Association))?
+        .elements()
+        .iter()
+        .map(|entry| {
+            let rule = entry
+                .try_normal_head(&sym!(Rule))
+                .or_else(|| entry.try_normal_head(&sym!(RuleDelayed)))?;
+
+            match rule.elements() {
+                [key, value] => Some((key, value)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Finds the value of `key` in an association. Should the key appear more than once the last
+/// entry wins, as later entries replace earlier ones. Returns `None` if `assoc` is not an
+/// association, and `Some(None)` if the key is absent.
+fn lookup(assoc: &Expr, key: &Expr) -> Option<Option<Expr>> {
+    let entries = association_entries(assoc)?;
+
+    Some(
+        entries
+            .into_iter()
+            .rev()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map(|(_, value)| value.clone()),
+    )
+}
+
+/// Builds `Missing["KeyAbsent", key]`, the result of looking up a key which is absent.
+fn missing(key: &Expr) -> Expr {
+    Expr::from(Normal::new(
+        sym!(Missing),
+        vec![Expr::from("KeyAbsent"), key.clone()],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    mod association {
+        evaluate_test!(present, "<|a -> 1, b -> 2|>[b]", "2");
+        evaluate_test!(delayed, "<|a :> 1 + 1|>[a]", "2");
+        evaluate_test!(absent, "<|a -> 1|>[c]", "Missing[\"KeyAbsent\", c]");
+        evaluate_test!(evaluated_key, ["x = 1"], "<|1 -> a, 2 -> b|>[x + 1]", "b");
+    }
+
+    mod lookup {
+        evaluate_test!(present, "Lookup[<|a -> 1|>, a]", "1");
+        evaluate_test!(absent, "Lookup[<|a -> 1|>, b]", "Missing[\"KeyAbsent\", b]");
+        evaluate_test!(default, "Lookup[<|a -> 1|>, b, 0]", "0");
+        evaluate_test!(default_unused, "Lookup[<|a -> 1|>, a, 0]", "1");
+        evaluate_test!(compound_key, "Lookup[<|{1, 2} -> x|>, {1, 2}]", "x");
+        evaluate_test!(last_entry_wins, "Lookup[<|a -> 1, a -> 2|>, a]", "2");
+        evaluate_test!(
+            not_an_association,
+            "Lookup[f[a -> 1], a]",
+            "Lookup[f[a -> 1], a]"
+        );
+    }
+
+    mod keys {
+        evaluate_test!(rules, "Keys[<|a -> 1, b -> 2|>]", "{a, b}");
+        evaluate_test!(delayed_rules, "Keys[<|a :> 1, b -> 2|>]", "{a, b}");
+        evaluate_test!(empty, "Keys[<||>]", "{}");
+        evaluate_test!(not_an_association, "Keys[{a -> 1}]", "Keys[{a -> 1}]");
+    }
+
+    mod values {
+        evaluate_test!(rules, "Values[<|a -> 1, b -> 2|>]", "{1, 2}");
+        evaluate_test!(delayed_rules, "Values[<|a :> 1 + 1, b -> 2|>]", "{2, 2}");
+        evaluate_test!(empty, "Values[<||>]", "{}");
+        evaluate_test!(not_an_association, "Values[x]", "Values[x]");
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    Attribute, Attributes, Context, EvalResult, Expr, Normal, Symbol, SymbolValue, ValueType,
+};
+use crate::{parse, sym};
+
+/// Registers the `Attributes` builtin symbol.
+///
+/// - `Attributes[Attributes] = { ReadOnly, AttributesReadOnly, HoldAll }`
+/// - `Attributes[s_Symbol] := built-in`
+pub(crate) fn register_attributes_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Attributes),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Attributes[s_Symbol]"),
+                condition: None,
+                built_in: |arguments, _, context| {
+                    let symbol = arguments[&Symbol::new("s")].try_symbol().unwrap();
+
+                    let mut names = context
+                        .get_attributes(symbol)
+                        .iter()
+                        .map(|attribute| attribute.name())
+                        .collect::<Vec<_>>();
+
+                    names.sort();
+
+                    let elements = names
+                        .into_iter()
+                        .map(|name| Expr::from(Symbol::new(name)))
+                        .collect::<Vec<_>>();
+
+                    EvalResult::Changed(Expr::from(Normal::new(sym!(List), elements)))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Attributes),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
+/// Registers the `SetAttributes` builtin symbol.
+///
+/// - `Attributes[SetAttributes] = { ReadOnly, AttributesReadOnly, HoldFirst }`
+/// - `SetAttributes[s_Symbol, attrs_] := built-in`
+pub(crate) fn register_set_attributes_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(SetAttributes),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("SetAttributes[s_Symbol, attrs_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let symbol = arguments[&Symbol::new("s")].try_symbol().unwrap();
+
+                    let Some(new_attributes) = parse_attributes(&arguments[&Symbol::new("attrs")])
+                    else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    let attributes = context.get_attributes(symbol) + new_attributes;
+
+                    match context.set_attributes(symbol, attributes) {
+                        Ok(()) => Ok(EvalResult::Changed(Expr::from(sym!(Null)))),
+                        Err(_) => Ok(EvalResult::Unchanged(expr)),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(SetAttributes),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldFirst,
+        )
+        .unwrap();
+}
+
+/// Registers the `ClearAttributes` builtin symbol.
+///
+/// - `Attributes[ClearAttributes] = { ReadOnly, AttributesReadOnly, HoldFirst }`
+/// - `ClearAttributes[s_Symbol, attrs_] := built-in`
+pub(crate) fn register_clear_attributes_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(ClearAttributes),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("ClearAttributes[s_Symbol, attrs_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let symbol = arguments[&Symbol::new("s")].try_symbol().unwrap();
+
+                    let Some(old_attributes) = parse_attributes(&arguments[&Symbol::new("attrs")])
+                    else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    let mut attributes = context.get_attributes(symbol);
+                    attributes.unset_all(old_attributes);
+
+                    match context.set_attributes(symbol, attributes) {
+                        Ok(()) => Ok(EvalResult::Changed(Expr::from(sym!(Null)))),
+                        Err(_) => Ok(EvalResult::Unchanged(expr)),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(ClearAttributes),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldFirst,
+        )
+        .unwrap();
+}
+
+/// Parses either a single attribute symbol or a list of attribute symbols, returning `None` if
+/// any of them do not name an attribute.
+fn parse_attributes(expr: &Expr) -> Option<Attributes> {
+    let elements = match expr.try_normal_head(&sym!(List)) {
+        Some(list) => list.elements(),
+        None => std::slice::from_ref(expr),
+    };
+
+    let mut attributes = Attributes::empty();
+    for element in elements {
+        attributes.set(Attribute::from_name(element.try_symbol()?.as_str())?);
+    }
+
+    Some(attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    mod attributes {
+        evaluate_test!(
+            plus,
+            "Attributes[Plus]",
+            "{Flat, Listable, Locked, OneIdentity, Orderless, Protected}"
+        );
+        evaluate_test!(
+            set,
+            "Attributes[Set]",
+            "{HoldFirst, Locked, Protected, SequenceHold}"
+        );
+        evaluate_test!(undefined_symbol, "Attributes[x]", "{}");
+        evaluate_test!(held_argument, ["x = 1"], "Attributes[x]", "{}");
+        evaluate_test!(non_symbol, "Attributes[1]", "Attributes[1]");
+    }
+
+    mod set_attributes {
+        evaluate_test!(
+            orderless,
+            ["SetAttributes[f, Orderless]"],
+            "f[c, b, a]",
+            "f[a, b, c]"
+        );
+        evaluate_test!(
+            list,
+            ["SetAttributes[f, {Flat, Orderless}]"],
+            "Attributes[f]",
+            "{Flat, Orderless}"
+        );
+        evaluate_test!(
+            keeps_existing,
+            ["SetAttributes[f, Flat]", "SetAttributes[f, Orderless]"],
+            "Attributes[f]",
+            "{Flat, Orderless}"
+        );
+        evaluate_test!(returns_null, "SetAttributes[f, Flat]", "Null");
+        evaluate_test!(
+            unknown_attribute,
+            "SetAttributes[f, Foo]",
+            "SetAttributes[f, Foo]"
+        );
+        evaluate_test!(
+            attributes_read_only,
+            "SetAttributes[Plus, Listable]",
+            "SetAttributes[Plus, Listable]"
+        );
+    }
+
+    mod clear_attributes {
+        evaluate_test!(
+            orderless,
+            [
+                "SetAttributes[f, Orderless]",
+                "ClearAttributes[f, Orderless]"
+            ],
+            "f[c, b, a]",
+            "f[c, b, a]"
+        );
+        evaluate_test!(
+            keeps_others,
+            [
+                "SetAttributes[f, {Flat, Orderless}]",
+                "ClearAttributes[f, Flat]"
+            ],
+            "Attributes[f]",
+            "{Orderless}"
+        );
+        evaluate_test!(
+            attributes_read_only,
+            "ClearAttributes[Plus, Flat]",
+            "ClearAttributes[Plus, Flat]"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use super::{bool_symbol, try_complex};
+use crate::{Attribute, Context, EvalResult, Expr, ExprKind, Symbol, SymbolValue, ValueType};
+use crate::{parse, sym, try_sequence};
+use std::cmp::Ordering;
+
+/// Registers the `Less` builtin symbol.
+///
+/// - `Attributes[Less] = { ReadOnly, AttributesReadOnly }`
+/// - `Less[exprs___] := built-in`
+pub(crate) fn register_less_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Less),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Less[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    compare_chain(&arguments[&Symbol::new("exprs")], expr, Ordering::is_lt)
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Less),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Greater` builtin symbol.
+///
+/// - `Attributes[Greater] = { ReadOnly, AttributesReadOnly }`
+/// - `Greater[exprs___] := built-in`
+pub(crate) fn register_greater_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Greater),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Greater[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    compare_chain(&arguments[&Symbol::new("exprs")], expr, Ordering::is_gt)
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Greater),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `LessEqual` builtin symbol.
+///
+/// - `Attributes[LessEqual] = { ReadOnly, AttributesReadOnly }`
+/// - `LessEqual[exprs___] := built-in`
+pub(crate) fn register_less_equal_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(LessEqual),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("LessEqual[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    compare_chain(&arguments[&Symbol::new("exprs")], expr, Ordering::is_le)
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(LessEqual),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `GreaterEqual` builtin symbol.
+///
+/// - `Attributes[GreaterEqual] = { ReadOnly, AttributesReadOnly }`
+/// - `GreaterEqual[exprs___] := built-in`
+pub(crate) fn register_greater_equal_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(GreaterEqual),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("GreaterEqual[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    compare_chain(&arguments[&Symbol::new("exprs")], expr, Ordering::is_ge)
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(GreaterEqual),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Equal` builtin symbol.
+///
+/// - `Attributes[Equal] = { ReadOnly, AttributesReadOnly }`
+/// - `Equal[exprs___] := built-in`
+pub(crate) fn register_equal_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Equal),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Equal[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let exprs = &arguments[&Symbol::new("exprs")];
+                    let expr_elements =
+                        try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+                    let mut undecided = false;
+
+                    for pair in expr_elements.windows(2) {
+                        match compare_equal(&pair[0], &pair[1]) {
+                            Some(true) => {}
+                            Some(false) => return EvalResult::Changed(Expr::from(sym!(False))),
+                            None => undecided = true,
+                        }
+                    }
+
+                    if undecided {
+                        EvalResult::Unchanged(expr)
+                    } else {
+                        EvalResult::Changed(Expr::from(sym!(True)))
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Equal),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Unequal` builtin symbol.
+///
+/// - `Attributes[Unequal] = { ReadOnly, AttributesReadOnly }`
+/// - `Unequal[exprs___] := built-in`
+pub(crate) fn register_unequal_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Unequal),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Unequal[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let exprs = &arguments[&Symbol::new("exprs")];
+                    let expr_elements =
+                        try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+                    let mut undecided = false;
+
+                    // Every argument must be distinct from every other argument.
+                    for (idx, lhs) in expr_elements.iter().enumerate() {
+                        for rhs in &expr_elements[idx + 1..] {
+                            match compare_equal(lhs, rhs) {
+                                Some(true) => {
+                                    return EvalResult::Changed(Expr::from(sym!(False)));
+                                }
+                                Some(false) => {}
+                                None => undecided = true,
+                            }
+                        }
+                    }
+
+                    if undecided {
+                        EvalResult::Unchanged(expr)
+                    } else {
+                        EvalResult::Changed(Expr::from(sym!(True)))
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Unequal),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Checks that every adjacent pair of elements within the `exprs` sequence satisfies `predicate`.
+/// The expression is left unevaluated if any pair cannot be numerically compared.
+fn compare_chain(exprs: &Expr, expr: Expr, predicate: fn(Ordering) -> bool) -> EvalResult {
+    let expr_elements = try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+    let mut result = true;
+
+    for pair in expr_elements.windows(2) {
+        match compare_numeric(&pair[0], &pair[1]) {
+            Some(ordering) => result &= predicate(ordering),
+            None => return EvalResult::Unchanged(expr),
+        }
+    }
+
+    EvalResult::Changed(Expr::from(bool_symbol(result)))
+}
+
+/// Compares two numeric expressions, promoting exact numbers to reals when the kinds are mixed.
+/// Returns `None` if either expression is not numeric, or the values are unordered (NaN).
+pub(crate) fn compare_numeric(lhs: &Expr, rhs: &Expr) -> Option<Ordering> {
+    match (lhs.kind(), rhs.kind()) {
+        (ExprKind::Integer(lhs), ExprKind::Integer(rhs)) => Some(lhs.cmp(rhs)),
+        (ExprKind::Integer(lhs), ExprKind::Rational(rhs)) => lhs.partial_cmp(rhs),
+        (ExprKind::Rational(lhs), ExprKind::Integer(rhs)) => lhs.partial_cmp(rhs),
+        (ExprKind::Rational(lhs), ExprKind::Rational(rhs)) => Some(lhs.cmp(rhs)),
+        (ExprKind::Rational(lhs), ExprKind::Real(rhs)) => {
+            rhs.as_float().partial_cmp(lhs).map(Ordering::reverse)
+        }
+        (ExprKind::Real(lhs), ExprKind::Rational(rhs)) => lhs.as_float().partial_cmp(rhs),
+        (ExprKind::Integer(lhs), ExprKind::Real(rhs)) => {
+            rhs.as_float().partial_cmp(lhs).map(Ordering::reverse)
+        }
+        (ExprKind::Real(lhs), ExprKind::Integer(rhs)) => lhs.as_float().partial_cmp(rhs),
+        (ExprKind::Real(lhs), ExprKind::Real(rhs)) => lhs.as_float().partial_cmp(rhs.as_float()),
+        _ => None,
+    }
+}
+
+/// Determines whether two expressions are equal. Numbers are compared by value, so `1` and `1.0`
+/// are considered equal. Returns `None` if equality cannot be decided, such as between two
+/// distinct symbols.
+fn compare_equal(lhs: &Expr, rhs: &Expr) -> Option<bool> {
+    if let Some(ordering) = compare_numeric(lhs, rhs) {
+        return Some(ordering.is_eq());
+    }
+
+    if lhs == rhs {
+        return Some(true);
+    }
+
+    if let (Some((lhs_re, lhs_im)), Some((rhs_re, rhs_im))) = (try_complex(lhs), try_complex(rhs)) {
+        return Some(compare_equal(lhs_re, rhs_re)? && compare_equal(lhs_im, rhs_im)?);
+    }
+
+    match (lhs.kind(), rhs.kind()) {
+        (
+            ExprKind::String(_) | ExprKind::Integer(_) | ExprKind::Rational(_) | ExprKind::Real(_),
+            ExprKind::String(_) | ExprKind::Integer(_) | ExprKind::Rational(_) | ExprKind::Real(_),
+        ) => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod less {
+        evaluate_test!(integers, "1 < 2", "True");
+        evaluate_test!(equal_integers, "2 < 2", "False");
+        evaluate_test!(mixed, "1 < 1.5", "True");
+        evaluate_test!(mixed_reversed, "1.5 < 1", "False");
+        evaluate_test!(chain, "Less[1, 2, 3]", "True");
+        evaluate_test!(broken_chain, "Less[1, 3, 2]", "False");
+        evaluate_test!(symbolic, "1 < x", "Less[1, x]");
+        evaluate_test!(single_argument, "Less[1]", "True");
+    }
+
+    mod greater {
+        evaluate_test!(integers, "2 > 1", "True");
+        evaluate_test!(equal_integers, "2 > 2", "False");
+        evaluate_test!(mixed, "2.5 > 2", "True");
+        evaluate_test!(chain, "Greater[3, 2.5, 1]", "True");
+        evaluate_test!(symbolic, "x > 1", "Greater[x, 1]");
+    }
+
+    mod less_equal {
+        evaluate_test!(integers, "1 <= 2", "True");
+        evaluate_test!(equal_integers, "2 <= 2", "True");
+        evaluate_test!(mixed, "2 <= 2.0", "True");
+        evaluate_test!(chain, "LessEqual[1, 1, 2]", "True");
+        evaluate_test!(broken_chain, "LessEqual[1, 2, 1]", "False");
+    }
+
+    mod greater_equal {
+        evaluate_test!(integers, "2 >= 1", "True");
+        evaluate_test!(equal_integers, "2 >= 2", "True");
+        evaluate_test!(mixed, "1.5 >= 2", "False");
+        evaluate_test!(chain, "GreaterEqual[3, 3, 1.0]", "True");
+        evaluate_test!(symbolic, "x >= y", "GreaterEqual[x, y]");
+    }
+
+    mod equal {
+        evaluate_test!(integers, "1 == 1", "True");
+        evaluate_test!(different_integers, "1 == 2", "False");
+        evaluate_test!(complex, "Complex[1, 2] == Complex[1.0, 2]", "True");
+        evaluate_test!(different_complex, "Complex[1, 2] == Complex[1, 3]", "False");
+        evaluate_test!(integer_and_real, "1 == 1.0", "True");
+        evaluate_test!(strings, "\"a\" == \"a\"", "True");
+        evaluate_test!(string_and_integer, "\"a\" == 1", "False");
+        evaluate_test!(identical_symbols, "x == x", "True");
+        evaluate_test!(symbolic, "x == y", "Equal[x, y]");
+        evaluate_test!(chain, "Equal[1, 1.0, 1]", "True");
+        evaluate_test!(broken_chain, "Equal[1, 2, x]", "False");
+        evaluate_test!(differs_from_same_q, "1 === 1.0", "False");
+    }
+
+    mod unequal {
+        evaluate_test!(integers, "1 != 2", "True");
+        evaluate_test!(integer_and_real, "1 != 1.0", "False");
+        evaluate_test!(symbolic, "x != y", "Unequal[x, y]");
+        evaluate_test!(variadic_distinct, "Unequal[1, 2, 3]", "True");
+        evaluate_test!(variadic_repeated, "Unequal[1, 2, 1.0]", "False");
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Attribute, Context, EvalResult, Expr, ExprKind, Symbol, SymbolValue, ValueType};
+use crate::{BigInteger, Normal, parse, sym};
+
+/// Registers the `I` builtin symbol, the imaginary unit.
+///
+/// - `Attributes[I] = { ReadOnly, AttributesReadOnly }`
+/// - `I := Complex[0, 1]`
+pub(crate) fn register_i_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(I),
+            ValueType::OwnValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("I"),
+                condition: None,
+                built_in: |_, _, _| EvalResult::Changed(parse!("Complex[0, 1]")),
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(I),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Complex` builtin symbol.
+///
+/// Complex numbers are represented structurally as `Complex[re, im]`, where both parts are
+/// numbers. A complex number with an exact zero imaginary part is collapsed into its real part.
+///
+/// - `Attributes[Complex] = { ReadOnly, AttributesReadOnly }`
+/// - `Complex[re_, im_] := built-in`
+pub(crate) fn register_complex_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Complex),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Complex[re_, im_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let re = &arguments[&Symbol::new("re")];
+                    let im = &arguments[&Symbol::new("im")];
+
+                    match im.try_integer() {
+                        Some(im) if im.is_zero() && is_real_number(re) => {
+                            EvalResult::Changed(re.clone())
+                        }
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Complex),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Determines whether the expression is an integer, rational or real.
+pub(crate) fn is_real_number(expr: &Expr) -> bool {
+    matches!(
+        expr.kind(),
+        ExprKind::Integer(_) | ExprKind::Rational(_) | ExprKind::Real(_)
+    )
+}
+
+/// Returns the real and imaginary parts of a complex number `Complex[re, im]`.
+pub(crate) fn try_complex(expr: &Expr) -> Option<(&Expr, &Expr)> {
+    match expr.try_normal_head(&sym!(Complex))?.elements() {
+        [re, im] if is_real_number(re) && is_real_number(im) => Some((re, im)),
+        _ => None,
+    }
+}
+
+/// Folds the numbers within the elements of `Plus[...]` into a single complex number, so long as
+/// at least one of them is complex and there is more than one number to fold. The resulting parts
+/// are left unevaluated as sums of their components.
+pub(crate) fn fold_complex_sum(elements: &[Expr]) -> Option<Vec<Expr>> {
+    let (numbers, rest) = partition_numbers(elements)?;
+
+    let mut re = Vec::with_capacity(numbers.len());
+    let mut im = Vec::with_capacity(numbers.len());
+
+    for number in numbers {
+        match try_complex(number) {
+            Some((a, b)) => {
+                re.push(a.clone());
+                im.push(b.clone());
+            }
+            None => re.push(number.clone()),
+        }
+    }
+
+    let sum = complex(
+        Expr::from(Normal::new(sym!(Plus), re)),
+        Expr::from(Normal::new(sym!(Plus), im)),
+    );
+
+    Some([vec![sum], rest].concat())
+}
+
+/// Folds the numbers within the elements of `Times[...]` into a single complex number, so long as
+/// at least one of them is complex and there is more than one number to fold. The resulting parts
+/// are left unevaluated as sums and products of their components.
+pub(crate) fn fold_complex_product(elements: &[Expr]) -> Option<Vec<Expr>> {
+    let (numbers, rest) = partition_numbers(elements)?;
+
+    let mut re = Expr::from(BigInteger::ONE.clone());
+    let mut im = Expr::from(BigInteger::new());
+
+    for number in numbers {
+        let (c, d) = match try_complex(number) {
+            Some((c, d)) => (c.clone(), d.clone()),
+            None => (number.clone(), Expr::from(BigInteger::new())),
+        };
+
+        // (a + b i)(c + d i) = (ac - bd) + (ad + bc) i
+        let new_re = plus(vec![
+            times(vec![re.clone(), c.clone()]),
+            times(vec![
+                Expr::from(BigInteger::NEG_ONE.clone()),
+                im.clone(),
+                d.clone(),
+            ]),
+        ]);
+        let new_im = plus(vec![times(vec![re, d]), times(vec![im, c])]);
+
+        re = new_re;
+        im = new_im;
+    }
+
+    Some([vec![complex(re, im)], rest].concat())
+}
+
+/// Splits the elements into the numbers, including complex numbers, and everything else. Returns
+/// `None` unless there are at least two numbers, one of which is complex.
+fn partition_numbers(elements: &[Expr]) -> Option<(Vec<&Expr>, Vec<Expr>)> {
+    let (numbers, rest): (Vec<_>, Vec<_>) = elements
+        .iter()
+        .partition(|element| is_real_number(element) || try_complex(element).is_some());
+
+    if numbers.len() < 2 || !numbers.iter().any(|number| try_complex(number).is_some()) {
+        return None;
+    }
+
+    Some((numbers, rest.into_iter().cloned().collect()))
+}
+
+fn complex(re: Expr, im: Expr) -> Expr {
+    Expr::from(Normal::new(sym!(Complex), vec![re, im]))
+}
+
+fn plus(elements: Vec<Expr>) -> Expr {
+    Expr::from(Normal::new(sym!(Plus), elements))
+}
+
+fn times(elements: Vec<Expr>) -> Expr {
+    Expr::from(Normal::new(sym!(Times), elements))
+}
+
+#[cfg(test)]
+mod tests {
+    mod i {
+        evaluate_test!(value, "I", "Complex[0, 1]");
+        evaluate_test!(squared, "I * I", "-1");
+        evaluate_test!(power, "I^2", "-1");
+        evaluate_test!(cubed, "I * I * I", "Complex[0, -1]");
+        evaluate_test!(reciprocal, "1 / I", "Complex[0, -1]");
+        evaluate_test!(
+            negative_power,
+            "SameQ[(1 + I)^-2, Complex[0, -1/2]]",
+            "True"
+        );
+        evaluate_test!(square_root_of_minus_one, "Power[-1, 1/2]", "Complex[0, 1]");
+    }
+
+    mod complex {
+        evaluate_test!(exact_zero_imaginary, "Complex[2, 0]", "2");
+        evaluate_test!(inexact_zero_imaginary, "Complex[2, 0.0]", "Complex[2, 0.0]");
+        evaluate_test!(symbolic, "Complex[a, 0]", "Complex[a, 0]");
+        evaluate_test!(sum, "2 + 3 * I", "Complex[2, 3]");
+        evaluate_test!(
+            sum_of_complex,
+            "(1 + 2 * I) + (3 - 4 * I)",
+            "Complex[4, -2]"
+        );
+        evaluate_test!(cancelling_sum, "(1 + 2 * I) - 2 * I", "1");
+        evaluate_test!(product, "(1 + 2 * I) * (3 + 4 * I)", "Complex[-5, 10]");
+        evaluate_test!(power, "(2 + 3 * I)^3", "Complex[-46, 9]");
+        evaluate_test!(conjugate_product, "(2 + 3 * I) * (2 - 3 * I)", "13");
+        evaluate_test!(scalar_product, "2 * (1 + I)", "Complex[2, 2]");
+        evaluate_test!(real_product, "0.5 * (2 + 4 * I)", "Complex[1.0, 2.0]");
+        evaluate_test!(symbolic_sum, "x + 2 * I + 1", "Plus[x, Complex[1, 2]]");
+        evaluate_test!(symbolic_product, "x * I * 2", "Times[x, Complex[0, 2]]");
+        evaluate_test!(equal, "2 + 3 * I == 2 + 3 * I", "True");
+        evaluate_test!(number_q, "NumberQ[2 + 3 * I]", "True");
+    }
+}
@@ -0,0 +1,121 @@
+use crate::sym;
+use crate::{
+    Context, Expr, GLOBAL_CONTEXT, Normal, SYSTEM_CONTEXT, Symbol, SymbolValue, ValueType,
+};
+
+/// Registers the `$Context` builtin symbol, the context symbols are created in when parsed without
+/// a qualifying context.
+///
+/// - `$Context = "Global`"`
+pub(crate) fn register_context_builtin(context: &mut Context) {
+    let symbol = Symbol::new("$Context");
+
+    context
+        .set_value(
+            &symbol,
+            ValueType::OwnValue,
+            SymbolValue::Definitions {
+                pattern: Expr::from(symbol.clone()),
+                condition: None,
+                ground: Expr::from(GLOBAL_CONTEXT),
+            },
+        )
+        .unwrap();
+}
+
+/// Registers the `$ContextPath` builtin symbol, the contexts searched for symbols parsed without a
+/// qualifying context.
+///
+/// - `$ContextPath = {"System`", "Global`"}`
+pub(crate) fn register_context_path_builtin(context: &mut Context) {
+    let symbol = Symbol::new("$ContextPath");
+
+    context
+        .set_value(
+            &symbol,
+            ValueType::OwnValue,
+            SymbolValue::Definitions {
+                pattern: Expr::from(symbol.clone()),
+                condition: None,
+                ground: Expr::from(Normal::new(
+                    sym!(List),
+                    vec![Expr::from(SYSTEM_CONTEXT), Expr::from(GLOBAL_CONTEXT)],
+                )),
+            },
+        )
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Context, Expr, Symbol, evaluate, parse_str, parse_str_with_context};
+
+    fn run(input: &str, context: &mut Context) -> Expr {
+        let expr = parse_str_with_context(input, context).unwrap();
+        evaluate(expr, context).unwrap()
+    }
+
+    #[test]
+    fn defaults() {
+        let mut context = Context::new_global_context();
+
+        assert_eq!(
+            run("$Context", &mut context),
+            parse_str("\"Global`\"").unwrap()
+        );
+        assert_eq!(
+            run("$ContextPath", &mut context),
+            parse_str("{\"System`\", \"Global`\"}").unwrap()
+        );
+        assert_eq!(context.resolve_symbol("x"), Symbol::new("x"));
+        assert_eq!(context.resolve_symbol("Global`x"), Symbol::new("x"));
+        assert_eq!(context.resolve_symbol("System`Plus"), Symbol::new("Plus"));
+    }
+
+    #[test]
+    fn symbols_created_in_current_context() {
+        let mut context = Context::new_global_context();
+
+        run("$Context = \"A`\"", &mut context);
+        run("x = 1", &mut context);
+
+        assert_eq!(context.resolve_symbol("x"), Symbol::new("A`x"));
+        assert_eq!(run("x", &mut context), parse_str("1").unwrap());
+        assert_eq!(run("A`x", &mut context), parse_str("1").unwrap());
+
+        // Builtins are still found through the context path.
+        assert_eq!(run("x + 1", &mut context), parse_str("2").unwrap());
+    }
+
+    #[test]
+    fn resolved_through_context_path() {
+        let mut context = Context::new_global_context();
+
+        run("$Context = \"A`\"", &mut context);
+        run("x = 1", &mut context);
+        run("$Context = \"B`\"", &mut context);
+
+        assert_eq!(context.resolve_symbol("x"), Symbol::new("B`x"));
+
+        run(
+            "$ContextPath = {\"A`\", \"System`\", \"Global`\"}",
+            &mut context,
+        );
+
+        assert_eq!(run("x", &mut context), parse_str("1").unwrap());
+    }
+
+    #[test]
+    fn contexts_do_not_clash() {
+        let mut context = Context::new_global_context();
+
+        run("f[x_] := global", &mut context);
+        run("Private`f[x_] := private", &mut context);
+
+        assert_eq!(run("f[1]", &mut context), parse_str("global").unwrap());
+        assert_eq!(
+            run("Private`f[1]", &mut context),
+            parse_str("private").unwrap()
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{Attribute, Context, EvalResult, Expr, Symbol, SymbolValue, ValueType};
+use crate::{parse, parse_str_with_context, sym};
+
+/// Registers the `FullForm` builtin symbol, which renders an expression as a string in its
+/// canonical `head[elements]` structure.
+///
+/// - `Attributes[FullForm] = { ReadOnly, AttributesReadOnly }`
+/// - `FullForm[expr_] := built-in`
+pub(crate) fn register_full_form_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(FullForm),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("FullForm[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    EvalResult::Changed(Expr::from(expr.to_string()))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(FullForm),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `InputForm` builtin symbol, which renders an expression as a string in the infix
+/// syntax accepted by the parser.
+///
+/// - `Attributes[InputForm] = { ReadOnly, AttributesReadOnly }`
+/// - `InputForm[expr_] := built-in`
+pub(crate) fn register_input_form_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(InputForm),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("InputForm[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    EvalResult::Changed(Expr::from(expr.to_infix()))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(InputForm),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `ToString` builtin symbol, which renders an expression as a string in infix form.
+/// Strings are left as they are, rather than quoted.
+///
+/// - `Attributes[ToString] = { ReadOnly, AttributesReadOnly }`
+/// - `ToString[expr_] := built-in`
+pub(crate) fn register_to_string_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(ToString),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("ToString[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    match expr.try_string() {
+                        Some(_) => EvalResult::Changed(expr.clone()),
+                        None => EvalResult::Changed(Expr::from(expr.to_infix())),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(ToString),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `ToExpression` builtin symbol, which parses a string into an expression, resolving
+/// its symbols against the current context. Gives `$Failed` if the string cannot be parsed, for
+/// example if it is nested more than [`MAX_NESTING_DEPTH`](crate::MAX_NESTING_DEPTH) levels deep,
+/// emitting a `ToExpression::sntx` message with the reason.
+///
+/// - `Attributes[ToExpression] = { ReadOnly, AttributesReadOnly }`
+/// - `ToExpression[s_String] := built-in`
+pub(crate) fn register_to_expression_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(ToExpression),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("ToExpression[s_String]"),
+                condition: None,
+                built_in: |arguments, _, context| {
+                    let string = arguments[&Symbol::new("s")].try_string().unwrap();
+
+                    match parse_str_with_context(string, context) {
+                        Ok(expr) => Ok(EvalResult::Changed(expr)),
+                        Err(error) => {
+                            context.emit_message("ToExpression::sntx", error);
+
+                            Ok(EvalResult::Changed(Expr::from(Symbol::new("$Failed"))))
+                        }
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(ToExpression),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    mod full_form {
+        evaluate_test!(plus, "FullForm[a + b]", "\"Plus[a, b]\"");
+        evaluate_test!(nested, "FullForm[a + b * c]", "\"Plus[a, Times[b, c]]\"");
+        evaluate_test!(evaluates_argument, "FullForm[1 + 2]", "\"3\"");
+        evaluate_test!(string, "FullForm[\"a\"]", "\"\\\"a\\\"\"");
+    }
+
+    mod input_form {
+        evaluate_test!(plus, "InputForm[Plus[a, b]]", "\"a + b\"");
+        evaluate_test!(nested, "InputForm[Times[a + b, c]]", "\"c*(a + b)\"");
+        evaluate_test!(function, "InputForm[Function[# + 1]]", "\"# + 1 &\"");
+        evaluate_test!(list, "InputForm[{a, b^2}]", "\"{a, b^2}\"");
+    }
+
+    mod to_string {
+        evaluate_test!(evaluated, "ToString[Plus[1, 2]]", "\"3\"");
+        evaluate_test!(infix, "ToString[Hold[a + b*c]]", "\"Hold[a + b*c]\"");
+        evaluate_test!(string, "ToString[\"abc\"]", "\"abc\"");
+        evaluate_test!(symbol, "ToString[x]", "\"x\"");
+    }
+
+    mod to_expression {
+        use crate::{Context, Expr, Symbol, evaluate, parse_str};
+
+        evaluate_test!(evaluated, "ToExpression[\"1 + 2\"]", "3");
+        evaluate_test!(symbolic, "ToExpression[\"f[x, y]\"]", "f[x, y]");
+        evaluate_test!(uses_definitions, ["x = 5"], "ToExpression[\"x + 1\"]", "6");
+        evaluate_test!(parse_error, "ToExpression[\"1 +\"]", "$Failed");
+        evaluate_test!(not_a_string, "ToExpression[x]", "ToExpression[x]");
+        evaluate_test!(
+            round_trip,
+            "ToExpression[ToString[Hold[a + b*c]]]",
+            "Hold[a + b*c]"
+        );
+
+        #[test]
+        fn nesting_limit_message() {
+            let mut context = Context::new_global_context();
+            let nested = format!("{}x{}", "f[".repeat(100), "]".repeat(100));
+            let input = format!("ToExpression[\"{}\"]", nested);
+
+            let result = evaluate(parse_str(&input).unwrap(), &mut context).unwrap();
+            let messages = context.take_messages();
+
+            assert_eq!(result, Expr::from(Symbol::new("$Failed")));
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages[0].tag, "ToExpression::sntx");
+            assert!(messages[0].text.contains("levels deep"));
+        }
+    }
+}
@@ -0,0 +1,937 @@
+use super::parse_rule;
+use crate::{
+    Attribute, BigInteger, Context, EvalError, EvalResult, Expr, Matcher, Normal, Symbol,
+    SymbolValue, ValueType,
+};
+use crate::{evaluate, parse, replace_all, sym, try_sequence};
+
+/// Registers the `First` builtin symbol.
+///
+/// - `Attributes[First] = { ReadOnly, AttributesReadOnly }`
+/// - `First[expr_] := built-in`
+pub(crate) fn register_first_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(First),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("First[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let list = &arguments[&Symbol::new("expr")];
+
+                    match list.try_normal() {
+                        Some(normal) if !normal.is_empty() => {
+                            EvalResult::Changed(normal.elements()[0].clone())
+                        }
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(First),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Last` builtin symbol.
+///
+/// - `Attributes[Last] = { ReadOnly, AttributesReadOnly }`
+/// - `Last[expr_] := built-in`
+pub(crate) fn register_last_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Last),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Last[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let list = &arguments[&Symbol::new("expr")];
+
+                    match list.try_normal() {
+                        Some(normal) if !normal.is_empty() => {
+                            EvalResult::Changed(normal.elements()[normal.len() - 1].clone())
+                        }
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Last),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Rest` builtin symbol.
+///
+/// - `Attributes[Rest] = { ReadOnly, AttributesReadOnly }`
+/// - `Rest[expr_] := built-in`
+pub(crate) fn register_rest_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Rest),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Rest[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let list = &arguments[&Symbol::new("expr")];
+
+                    match list.try_normal() {
+                        Some(normal) if !normal.is_empty() => EvalResult::Changed(Expr::from(
+                            Normal::new(normal.head().clone(), &normal.elements()[1..]),
+                        )),
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Rest),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Most` builtin symbol.
+///
+/// - `Attributes[Most] = { ReadOnly, AttributesReadOnly }`
+/// - `Most[expr_] := built-in`
+pub(crate) fn register_most_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Most),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Most[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let list = &arguments[&Symbol::new("expr")];
+
+                    match list.try_normal() {
+                        Some(normal) if !normal.is_empty() => {
+                            EvalResult::Changed(Expr::from(Normal::new(
+                                normal.head().clone(),
+                                &normal.elements()[..normal.len() - 1],
+                            )))
+                        }
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Most),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Append` builtin symbol.
+///
+/// - `Attributes[Append] = { ReadOnly, AttributesReadOnly }`
+/// - `Append[expr_, x_] := built-in`
+pub(crate) fn register_append_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Append),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Append[expr_, x_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let list = &arguments[&Symbol::new("expr")];
+                    let x = &arguments[&Symbol::new("x")];
+
+                    match list.try_normal() {
+                        Some(normal) => {
+                            let mut new_elements = Vec::with_capacity(normal.len() + 1);
+                            new_elements.extend_from_slice(normal.elements());
+                            new_elements.push(x.clone());
+
+                            EvalResult::Changed(Expr::from(Normal::new(
+                                normal.head().clone(),
+                                new_elements,
+                            )))
+                        }
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Append),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Prepend` builtin symbol.
+///
+/// - `Attributes[Prepend] = { ReadOnly, AttributesReadOnly }`
+/// - `Prepend[expr_, x_] := built-in`
+pub(crate) fn register_prepend_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Prepend),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Prepend[expr_, x_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let list = &arguments[&Symbol::new("expr")];
+                    let x = &arguments[&Symbol::new("x")];
+
+                    match list.try_normal() {
+                        Some(normal) => {
+                            let mut new_elements = Vec::with_capacity(normal.len() + 1);
+                            new_elements.push(x.clone());
+                            new_elements.extend_from_slice(normal.elements());
+
+                            EvalResult::Changed(Expr::from(Normal::new(
+                                normal.head().clone(),
+                                new_elements,
+                            )))
+                        }
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Prepend),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Part` builtin symbol. A `Part::partw` message is emitted for an index which is
+/// out of range.
+///
+/// - `Attributes[Part] = { ReadOnly, AttributesReadOnly }`
+/// - `Part[expr_, indices__] := built-in`
+pub(crate) fn register_part_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Part),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Part[expr_, indices__]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let list = &arguments[&Symbol::new("expr")];
+                    let indices = &arguments[&Symbol::new("indices")];
+                    let indices =
+                        try_sequence(indices).expect("expected indices__ to match Sequence[]");
+
+                    let mut current = list.clone();
+
+                    for index in indices {
+                        match part(&current, index) {
+                            Some(next) => current = next,
+                            None => {
+                                if index.try_integer().is_some() {
+                                    context.emit_message(
+                                        "Part::partw",
+                                        format!(
+                                            "Part {} of {} does not exist.",
+                                            index,
+                                            current.to_infix()
+                                        ),
+                                    );
+                                }
+
+                                return Ok(EvalResult::Unchanged(expr));
+                            }
+                        }
+                    }
+
+                    Ok(EvalResult::Changed(current))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Part),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Extracts a single part of `expr`. Index `0` refers to the head, positive indices are 1-based,
+/// and negative indices count backwards from the last element. Returns `None` if the index is
+/// not an integer or is out of range.
+fn part(expr: &Expr, index: &Expr) -> Option<Expr> {
+    let index = index.try_integer()?.to_isize()?;
+
+    if index == 0 {
+        return Some(expr.head());
+    }
+
+    let normal = expr.try_normal()?;
+
+    let offset = if index > 0 {
+        index - 1
+    } else {
+        normal.len() as isize + index
+    };
+
+    usize::try_from(offset)
+        .ok()
+        .and_then(|offset| normal.element(offset))
+        .cloned()
+}
+
+/// Registers the `Sort` builtin symbol.
+///
+/// - `Attributes[Sort] = { ReadOnly, AttributesReadOnly }`
+/// - `Sort[expr_] := built-in`
+/// - `Sort[expr_, cmp_] := built-in`
+pub(crate) fn register_sort_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Sort),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Sort[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let list = &arguments[&Symbol::new("expr")];
+
+                    match list.try_normal() {
+                        Some(normal) => {
+                            let mut new_elements = normal.elements().to_vec();
+                            new_elements.sort();
+
+                            EvalResult::Changed(Expr::from(Normal::new(
+                                normal.head().clone(),
+                                new_elements,
+                            )))
+                        }
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_value(
+            &sym!(Sort),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Sort[expr_, cmp_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let list = &arguments[&Symbol::new("expr")];
+                    let cmp = &arguments[&Symbol::new("cmp")];
+
+                    match list.try_normal() {
+                        Some(normal) => {
+                            let new_elements = merge_sort(normal.elements(), cmp, context)?;
+
+                            Ok(EvalResult::Changed(Expr::from(Normal::new(
+                                normal.head().clone(),
+                                new_elements,
+                            ))))
+                        }
+                        None => Ok(EvalResult::Unchanged(expr)),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Sort),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Sorts `elements` with a merge sort, ordering `a` before `b` only when `cmp[a, b]` evaluates to
+/// `True`. Elements are otherwise left in their original order. As the comparator is only ever
+/// asked about pairs in one direction, a comparator which is not a consistent ordering gives some
+/// permutation of the elements rather than failing.
+fn merge_sort(
+    elements: &[Expr],
+    cmp: &Expr,
+    context: &mut Context,
+) -> Result<Vec<Expr>, EvalError> {
+    if elements.len() <= 1 {
+        return Ok(elements.to_vec());
+    }
+
+    let (left, right) = elements.split_at(elements.len() / 2);
+    let left = merge_sort(left, cmp, context)?;
+    let right = merge_sort(right, cmp, context)?;
+
+    let mut merged = Vec::with_capacity(elements.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        // Taking from the left unless the right is strictly ordered first keeps the sort stable.
+        if is_ordered(cmp, r, l, context)? {
+            merged.push(right.next().unwrap());
+        } else {
+            merged.push(left.next().unwrap());
+        }
+    }
+
+    merged.extend(left);
+    merged.extend(right);
+
+    Ok(merged)
+}
+
+/// Whether `cmp[a, b]` evaluates to `True`.
+fn is_ordered(cmp: &Expr, a: &Expr, b: &Expr, context: &mut Context) -> Result<bool, EvalError> {
+    let ordered = evaluate(
+        Expr::from(Normal::new(cmp.clone(), vec![a.clone(), b.clone()])),
+        context,
+    )?;
+
+    Ok(ordered.try_symbol() == Some(&sym!(True)))
+}
+
+/// Registers the `Range` builtin symbol.
+///
+/// - `Attributes[Range] = { ReadOnly, AttributesReadOnly }`
+/// - `Range[n_] := built-in`
+/// - `Range[a_, b_] := built-in`
+/// - `Range[a_, b_, step_] := built-in`
+pub(crate) fn register_range_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Range),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Range[n_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let n = &arguments[&Symbol::new("n")];
+
+                    match n.try_integer() {
+                        Some(n) => range(BigInteger::ONE, n, BigInteger::ONE)
+                            .map_or(EvalResult::Unchanged(expr), EvalResult::Changed),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_value(
+            &sym!(Range),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Range[a_, b_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let a = &arguments[&Symbol::new("a")];
+                    let b = &arguments[&Symbol::new("b")];
+
+                    match (a.try_integer(), b.try_integer()) {
+                        (Some(a), Some(b)) => range(a, b, BigInteger::ONE)
+                            .map_or(EvalResult::Unchanged(expr), EvalResult::Changed),
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_value(
+            &sym!(Range),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Range[a_, b_, step_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let a = &arguments[&Symbol::new("a")];
+                    let b = &arguments[&Symbol::new("b")];
+                    let step = &arguments[&Symbol::new("step")];
+
+                    match (a.try_integer(), b.try_integer(), step.try_integer()) {
+                        (Some(a), Some(b), Some(step)) => range(a, b, step)
+                            .map_or(EvalResult::Unchanged(expr), EvalResult::Changed),
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Range),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Select` builtin symbol, which keeps the elements of an expression for which
+/// `crit[element]` evaluates to `True`, in their original order.
+///
+/// - `Attributes[Select] = { ReadOnly, AttributesReadOnly }`
+/// - `Select[expr_, crit_] := built-in`
+pub(crate) fn register_select_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Select),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Select[expr_, crit_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let list = &arguments[&Symbol::new("expr")];
+                    let crit = &arguments[&Symbol::new("crit")];
+
+                    let Some(normal) = list.try_normal() else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    let mut selected = Vec::new();
+                    for element in normal.elements() {
+                        let result = evaluate(
+                            Expr::from(Normal::new(crit.clone(), vec![element.clone()])),
+                            context,
+                        )?;
+
+                        if result.try_symbol() == Some(&sym!(True)) {
+                            selected.push(element.clone());
+                        }
+                    }
+
+                    Ok(EvalResult::Changed(Expr::from(Normal::new(
+                        normal.head().clone(),
+                        selected,
+                    ))))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Select),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Count` builtin symbol, which counts the elements of an expression matching a
+/// pattern. Only the elements themselves are tested, not any of their parts.
+///
+/// - `Attributes[Count] = { ReadOnly, AttributesReadOnly }`
+/// - `Count[expr_, pattern_] := built-in`
+pub(crate) fn register_count_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Count),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Count[expr_, pattern_]"),
+                condition: None,
+                built_in: |arguments, _, context| {
+                    let list = &arguments[&Symbol::new("expr")];
+                    let pattern = &arguments[&Symbol::new("pattern")];
+
+                    let elements = list
+                        .try_normal()
+                        .map_or(&[][..], |normal| normal.elements());
+                    let count = elements
+                        .iter()
+                        .filter(|element| {
+                            Matcher::new(pattern.clone(), (*element).clone(), context)
+                                .first()
+                                .is_some()
+                        })
+                        .count();
+
+                    Ok(EvalResult::Changed(Expr::from(BigInteger::from(count))))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Count),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Cases` builtin symbol, which lists the elements of an expression matching a
+/// pattern. Given a rule `lhs -> rhs` instead, the elements matching `lhs` are each replaced by
+/// `rhs` with the bindings of the match substituted in. As with `Count`, only the elements
+/// themselves are tested.
+///
+/// - `Attributes[Cases] = { ReadOnly, AttributesReadOnly }`
+/// - `Cases[expr_, pattern_] := built-in`
+pub(crate) fn register_cases_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Cases),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Cases[expr_, pattern_]"),
+                condition: None,
+                built_in: |arguments, _, context| {
+                    let list = &arguments[&Symbol::new("expr")];
+                    let pattern = &arguments[&Symbol::new("pattern")];
+
+                    let (lhs, rhs) = match parse_rule(pattern) {
+                        Some((lhs, rhs)) => (lhs, Some(rhs)),
+                        None => (pattern.clone(), None),
+                    };
+
+                    let elements = list
+                        .try_normal()
+                        .map_or(&[][..], |normal| normal.elements());
+                    let mut cases = Vec::new();
+
+                    for element in elements {
+                        let Some(bindings) =
+                            Matcher::new(lhs.clone(), element.clone(), context).first()
+                        else {
+                            continue;
+                        };
+
+                        cases.push(match &rhs {
+                            Some(rhs) => replace_all(&bindings, rhs.clone()).into_expr(),
+                            None => element.clone(),
+                        });
+                    }
+
+                    Ok(EvalResult::Changed(Expr::from(Normal::new(
+                        sym!(List),
+                        cases,
+                    ))))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Cases),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Total` builtin symbol, which sums the elements of a list, see [`total`].
+///
+/// - `Attributes[Total] = { ReadOnly, AttributesReadOnly }`
+/// - `Total[list_List] := built-in`
+/// - `Total[list_List, n_Integer] := built-in`
+pub(crate) fn register_total_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Total),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Total[list_List]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    EvalResult::Changed(total(&arguments[&Symbol::new("list")], 1))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_value(
+            &sym!(Total),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Total[list_List, n_Integer]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let list = &arguments[&Symbol::new("list")];
+                    let n = arguments[&Symbol::new("n")].try_integer().unwrap();
+
+                    match n.to_usize() {
+                        Some(levels) => EvalResult::Changed(total(list, levels)),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Total),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Wraps the elements of a list in `Plus`, doing the same for any nested lists down to `levels`
+/// levels deep. For example `total({{a, b}, {c}}, 2)` is `Plus[Plus[a, b], Plus[c]]`, leaving the
+/// sums to be evaluated. As `Plus` is `Listable`, summing only the first level of a matrix adds
+/// its rows together.
+fn total(expr: &Expr, levels: usize) -> Expr {
+    match expr.try_normal_head(&sym!(List)) {
+        Some(list) if levels > 0 => Expr::from(Normal::new(
+            sym!(Plus),
+            list.elements()
+                .iter()
+                .map(|element| total(element, levels - 1))
+                .collect::<Vec<_>>(),
+        )),
+        _ => expr.clone(),
+    }
+}
+
+/// Builds the list `{start, start + step, ...}` of every value up to and including `end`. Returns
+/// `None` if `step` is zero.
+fn range(start: &BigInteger, end: &BigInteger, step: &BigInteger) -> Option<Expr> {
+    if step.is_zero() {
+        return None;
+    }
+
+    let mut elements = Vec::new();
+    let mut current = start.clone();
+
+    while (step.is_positive() && current <= *end) || (step.is_negative() && current >= *end) {
+        elements.push(Expr::from(current.clone()));
+        current += step;
+    }
+
+    Some(Expr::from(Normal::new(sym!(List), elements)))
+}
+
+#[cfg(test)]
+mod tests {
+    mod first {
+        evaluate_test!(list, "First[{a, b, c}]", "a");
+        evaluate_test!(normal, "First[g[x, y]]", "x");
+        evaluate_test!(empty, "First[f[]]", "First[f[]]");
+        evaluate_test!(atom, "First[5]", "First[5]");
+    }
+
+    mod last {
+        evaluate_test!(list, "Last[{a, b, c}]", "c");
+        evaluate_test!(normal, "Last[g[x, y]]", "y");
+        evaluate_test!(empty, "Last[f[]]", "Last[f[]]");
+        evaluate_test!(atom, "Last[5]", "Last[5]");
+    }
+
+    mod rest {
+        evaluate_test!(list, "Rest[{a, b, c}]", "{b, c}");
+        evaluate_test!(normal, "Rest[g[x, y]]", "g[y]");
+        evaluate_test!(single_element, "Rest[{a}]", "{}");
+        evaluate_test!(empty, "Rest[f[]]", "Rest[f[]]");
+        evaluate_test!(atom, "Rest[5]", "Rest[5]");
+    }
+
+    mod most {
+        evaluate_test!(list, "Most[{a, b, c}]", "{a, b}");
+        evaluate_test!(normal, "Most[g[x, y]]", "g[x]");
+        evaluate_test!(single_element, "Most[{a}]", "{}");
+        evaluate_test!(empty, "Most[f[]]", "Most[f[]]");
+        evaluate_test!(atom, "Most[5]", "Most[5]");
+    }
+
+    mod append {
+        evaluate_test!(list, "Append[{1, 2}, 3]", "{1, 2, 3}");
+        evaluate_test!(normal, "Append[g[x, y], z]", "g[x, y, z]");
+        evaluate_test!(empty, "Append[f[], a]", "f[a]");
+        evaluate_test!(atom, "Append[5, a]", "Append[5, a]");
+    }
+
+    mod prepend {
+        evaluate_test!(list, "Prepend[{1, 2}, 3]", "{3, 1, 2}");
+        evaluate_test!(normal, "Prepend[g[x, y], z]", "g[z, x, y]");
+        evaluate_test!(empty, "Prepend[f[], a]", "f[a]");
+        evaluate_test!(atom, "Prepend[5, a]", "Prepend[5, a]");
+    }
+
+    mod part {
+        use crate::{Context, Message, evaluate, parse_str};
+
+        evaluate_test!(positive_index, "{a, b, c}[[2]]", "b");
+        evaluate_test!(negative_index, "{a, b, c}[[-1]]", "c");
+        evaluate_test!(head, "Part[g[x, y], 0]", "g");
+        evaluate_test!(nested, "{{1, 2}, {3, 4}}[[2, 1]]", "3");
+        evaluate_test!(applied_expression, "f[a, b][[2]]", "b");
+        evaluate_test!(out_of_range, "Part[{a, b, c}, 4]", "Part[{a, b, c}, 4]");
+        evaluate_test!(
+            negative_out_of_range,
+            "Part[{a, b, c}, -4]",
+            "Part[{a, b, c}, -4]"
+        );
+        evaluate_test!(atom, "Part[5, 1]", "Part[5, 1]");
+        evaluate_test!(symbolic_index, "Part[{a, b, c}, i]", "Part[{a, b, c}, i]");
+
+        #[test]
+        fn out_of_range_message() {
+            let mut context = Context::new_global_context();
+
+            evaluate(parse_str("{a, b, c}[[4]]").unwrap(), &mut context).unwrap();
+
+            assert_eq!(
+                context.take_messages(),
+                vec![Message {
+                    tag: String::from("Part::partw"),
+                    text: String::from("Part 4 of {a, b, c} does not exist."),
+                }]
+            );
+            assert!(context.messages().is_empty());
+        }
+
+        #[test]
+        fn message_emitted_once() {
+            let mut context = Context::new_global_context();
+
+            evaluate(parse_str("{{a, b, c}[[4]], y = 1}").unwrap(), &mut context).unwrap();
+            assert_eq!(context.take_messages().len(), 1);
+
+            // A later evaluation emits the message again.
+            evaluate(parse_str("{a, b, c}[[4]]").unwrap(), &mut context).unwrap();
+            evaluate(parse_str("{a, b, c}[[4]]").unwrap(), &mut context).unwrap();
+            assert_eq!(context.take_messages().len(), 2);
+        }
+
+        #[test]
+        fn no_message_in_range() {
+            let mut context = Context::new_global_context();
+
+            evaluate(parse_str("{a, b, c}[[2]]").unwrap(), &mut context).unwrap();
+            evaluate(parse_str("{a, b, c}[[i]]").unwrap(), &mut context).unwrap();
+
+            assert!(context.messages().is_empty());
+        }
+    }
+
+    mod sort {
+        use crate::{Context, EvalError, evaluate, parse_str};
+
+        evaluate_test!(integers, "Sort[{3, 1, 2}]", "{1, 2, 3}");
+        evaluate_test!(symbols, "Sort[f[c, a, b]]", "f[a, b, c]");
+        evaluate_test!(empty, "Sort[{}]", "{}");
+        evaluate_test!(atom, "Sort[5]", "Sort[5]");
+        evaluate_test!(comparator_less, "Sort[{3, 1, 2}, Less]", "{1, 2, 3}");
+        evaluate_test!(comparator_greater, "Sort[{3, 1, 2}, Greater]", "{3, 2, 1}");
+        evaluate_test!(comparator_ties, "Sort[{2, 1, 2}, Greater]", "{2, 2, 1}");
+        evaluate_test!(comparator_stable, "Sort[{b, a, c}, False &]", "{b, a, c}");
+        evaluate_test!(
+            inconsistent_comparator,
+            "Length[Sort[Range[300], Mod[#1 * #2, 11] < 5 &]]",
+            "300"
+        );
+
+        #[test]
+        fn comparator_error() {
+            let mut context = Context::new_global_context();
+
+            let result = evaluate(parse_str("Sort[{1, 2}, (1 = #1) &]").unwrap(), &mut context);
+
+            assert!(matches!(result, Err(EvalError::InvalidAssignment(_))));
+        }
+    }
+
+    mod range {
+        evaluate_test!(upper_bound, "Range[5]", "{1, 2, 3, 4, 5}");
+        evaluate_test!(bounds, "Range[2, 6]", "{2, 3, 4, 5, 6}");
+        evaluate_test!(step, "Range[1, 10, 3]", "{1, 4, 7, 10}");
+        evaluate_test!(negative_step, "Range[10, 2, -2]", "{10, 8, 6, 4, 2}");
+        evaluate_test!(empty, "Range[0]", "{}");
+        evaluate_test!(zero_step, "Range[1, 5, 0]", "Range[1, 5, 0]");
+        evaluate_test!(symbolic, "Range[n]", "Range[n]");
+    }
+
+    mod total {
+        evaluate_test!(integers, "Total[{1, 2, 3}]", "6");
+        evaluate_test!(symbolic, "Total[{b, a}]", "a + b");
+        evaluate_test!(empty, "Total[{}]", "0");
+        evaluate_test!(rows, "Total[{{1, 2}, {3, 4}}]", "{4, 6}");
+        evaluate_test!(levels, "Total[{{1, 2}, {3, 4}}, 2]", "10");
+        evaluate_test!(ragged, "Total[{{1, 2}, 3}, 2]", "6");
+        evaluate_test!(no_levels, "Total[{1, 2}, 0]", "{1, 2}");
+        evaluate_test!(not_a_list, "Total[x]", "Total[x]");
+        evaluate_test!(negative_levels, "Total[{1}, -1]", "Total[{1}, -1]");
+    }
+
+    mod select {
+        evaluate_test!(
+            user_predicate,
+            ["big[x_] := x > 2"],
+            "Select[{1, 2, 3, 4}, big]",
+            "{3, 4}"
+        );
+        evaluate_test!(
+            builtin_predicate,
+            "Select[{1, a, 2, b}, IntegerQ]",
+            "{1, 2}"
+        );
+        evaluate_test!(preserves_head, "Select[f[1, 2, 3], #1 < 3 &]", "f[1, 2]");
+        evaluate_test!(none_selected, "Select[{1, 2}, StringQ]", "{}");
+        evaluate_test!(only_true, "Select[{1, 2}, g]", "{}");
+        evaluate_test!(atom, "Select[x, IntegerQ]", "Select[x, IntegerQ]");
+    }
+
+    mod count {
+        evaluate_test!(blank_head, "Count[{1, a, 2, b}, _Integer]", "2");
+        evaluate_test!(literal, "Count[{a, b, a, c}, a]", "2");
+        evaluate_test!(condition, "Count[{1, 2, 3, 4}, x_ /; x > 2]", "2");
+        evaluate_test!(pattern_test, "Count[{1, 2, 3, 4}, _?EvenQ]", "2");
+        evaluate_test!(top_level_only, "Count[{1, {2, 3}}, _Integer]", "1");
+        evaluate_test!(any_head, "Count[f[1, x, 2], _Integer]", "2");
+        evaluate_test!(no_match, "Count[{a, b}, _String]", "0");
+        evaluate_test!(atom, "Count[1, _Integer]", "0");
+    }
+
+    mod cases {
+        evaluate_test!(blank_head, "Cases[{1, a, 2}, _Integer]", "{1, 2}");
+        evaluate_test!(literal, "Cases[{a, b, a}, a]", "{a, a}");
+        evaluate_test!(any_head, "Cases[f[1, x, g[2]], _g]", "{g[2]}");
+        evaluate_test!(top_level_only, "Cases[{1, {2}}, _Integer]", "{1}");
+        evaluate_test!(no_match, "Cases[{a, b}, _Integer]", "{}");
+        evaluate_test!(rule, "Cases[{f[1], g[2], f[3]}, f[x_] -> x]", "{1, 3}");
+        evaluate_test!(
+            delayed_rule,
+            "Cases[{1, a, 2}, x_Integer :> x + 1]",
+            "{2, 3}"
+        );
+        evaluate_test!(
+            rule_with_condition,
+            "Cases[{1, 2, 3}, x_ /; x > 1 -> {x}]",
+            "{{2}, {3}}"
+        );
+        evaluate_test!(list_pattern, "Cases[{{1, 2}, {3}}, {_, _}]", "{{1, 2}}");
+    }
+}
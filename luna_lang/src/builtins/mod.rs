@@ -1,31 +1,146 @@
+mod arithmetic;
+mod association;
+mod attributes;
+mod comparison;
+mod complex;
+mod contexts;
+mod forms;
+mod list;
+mod predicates;
+mod rules;
+mod scoping;
+mod string;
 mod symbols;
 
 use crate::{
-    Attribute, BigFloat, BigInteger, DEFAULT_REAL_PRECISION, EvalResult, SymbolValue, parse, sym,
-    try_sequence,
+    Attribute, BigFloat, BigInteger, BigRational, DEFAULT_REAL_PRECISION, EvalResult, SymbolValue,
+    parse, real_precision, sym, try_sequence,
 };
-use crate::{Context, Expr, SolutionSet};
-use crate::{ExprKind, Symbol, extract_condition};
+use crate::{Context, EvalError, Expr, SolutionSet, evaluate};
+use crate::{ExprKind, Symbol, extract_condition, strip_hold_pattern};
 use crate::{Normal, ValueType};
-use rug::ops::AddFrom;
+use rug::ops::{AddFrom, Pow};
 use std::ops::{AddAssign, MulAssign};
 
+use arithmetic::*;
+use association::*;
+use attributes::*;
+use comparison::*;
+use complex::*;
+use contexts::*;
+use forms::*;
+use list::*;
+use predicates::*;
+use rules::*;
+use scoping::*;
+use string::*;
 pub use symbols::*;
 
 pub type BuiltinFn = fn(SolutionSet, Expr, &Context) -> EvalResult;
-pub type BuiltinFnMut = fn(SolutionSet, Expr, &mut Context) -> EvalResult;
+pub type BuiltinFnMut = fn(SolutionSet, Expr, &mut Context) -> Result<EvalResult, EvalError>;
 
 /// Registers all builtins.
 pub(crate) fn register_builtins(context: &mut Context) {
     register_set_builtin(context);
     register_set_delayed_builtin(context);
+    register_tag_set_builtin(context);
+    register_tag_set_delayed_builtin(context);
+    register_hold_builtin(context);
+    register_release_hold_builtin(context);
+    register_evaluate_builtin(context);
+    register_hold_pattern_builtin(context);
+    register_clear_builtin(context);
+    register_unset_builtin(context);
+    register_remove_builtin(context);
+    register_attributes_builtin(context);
+    register_set_attributes_builtin(context);
+    register_clear_attributes_builtin(context);
     register_head_builtin(context);
     register_plus_builtin(context);
     register_times_builtin(context);
     register_subtract_builtin(context);
+    register_divide_builtin(context);
+    register_power_builtin(context);
+    register_factorial_builtin(context);
+    register_map_builtin(context);
+    register_apply_builtin(context);
+    register_nest_builtin(context);
+    register_nest_list_builtin(context);
+    register_function_builtin(context);
+    register_if_builtin(context);
+    register_compound_expression_builtin(context);
+    register_same_q_builtin(context);
+    register_unsame_q_builtin(context);
+    register_less_builtin(context);
+    register_greater_builtin(context);
+    register_less_equal_builtin(context);
+    register_greater_equal_builtin(context);
+    register_equal_builtin(context);
+    register_unequal_builtin(context);
+    register_integer_q_builtin(context);
+    register_number_q_builtin(context);
+    register_string_q_builtin(context);
+    register_symbol_q_builtin(context);
+    register_even_q_builtin(context);
+    register_odd_q_builtin(context);
+    register_positive_builtin(context);
+    register_negative_builtin(context);
+    register_length_builtin(context);
+    register_first_builtin(context);
+    register_last_builtin(context);
+    register_rest_builtin(context);
+    register_most_builtin(context);
+    register_append_builtin(context);
+    register_prepend_builtin(context);
+    register_part_builtin(context);
+    register_sort_builtin(context);
+    register_range_builtin(context);
+    register_total_builtin(context);
+    register_select_builtin(context);
+    register_count_builtin(context);
+    register_cases_builtin(context);
+    register_abs_builtin(context);
+    register_sqrt_builtin(context);
+    register_min_builtin(context);
+    register_max_builtin(context);
+    register_mod_builtin(context);
+    register_quotient_builtin(context);
+    register_gcd_builtin(context);
+    register_lcm_builtin(context);
+    register_rational_builtin(context);
+    register_complex_builtin(context);
+    register_i_builtin(context);
+    register_n_builtin(context);
+    register_rule_builtin(context);
+    register_rule_delayed_builtin(context);
+    register_replace_all_builtin(context);
+    register_replace_repeated_builtin(context);
+    register_full_form_builtin(context);
+    register_input_form_builtin(context);
+    register_to_string_builtin(context);
+    register_to_expression_builtin(context);
+    register_block_builtin(context);
+    register_module_builtin(context);
+    register_context_builtin(context);
+    register_context_path_builtin(context);
+    register_string_join_builtin(context);
+    register_string_length_builtin(context);
+    register_string_take_builtin(context);
+    register_string_drop_builtin(context);
+    register_association_builtin(context);
+    register_lookup_builtin(context);
+    register_keys_builtin(context);
+    register_values_builtin(context);
+
+    // Every builtin symbol exists, even those without any values or attributes, so that they are
+    // found when resolving symbols against the context path.
+    for symbol in BUILTIN_SYMBOLS.all() {
+        context.get_definition_mut(symbol);
+    }
 }
 
-/// Registers the `Set` builtin symbol.
+/// Registers the `Set` builtin symbol. The right hand side is evaluated before the definition is
+/// stored, and is returned as the result.
 ///
 /// - `Attributes[Set] = { ReadOnly, AttributesReadOnly, HoldFirst, HoldSequences }`
 /// - `Set[lhs_, rhs_] := built-in`
@@ -41,9 +156,9 @@ pub(crate) fn register_set_builtin(context: &mut Context) {
                     let pattern = &arguments[&Symbol::new("lhs")];
                     let ground = &arguments[&Symbol::new("rhs")];
 
-                    declare_rule(pattern, ground, context);
+                    declare_rule(pattern, ground, context)?;
 
-                    EvalResult::Changed(Expr::from(Normal::new(sym!(Hold), vec![ground.clone()])))
+                    Ok(EvalResult::Changed(ground.clone()))
                 },
             },
         )
@@ -60,7 +175,8 @@ pub(crate) fn register_set_builtin(context: &mut Context) {
         .unwrap();
 }
 
-/// Registers the `SetDelayed` builtin symbol.
+/// Registers the `SetDelayed` builtin symbol. The right hand side is stored unevaluated, and is
+/// evaluated each time the definition is used.
 ///
 /// - `Attributes[SetDelayed] = { ReadOnly, AttributesReadOnly, HoldAll, HoldSequences }`
 /// - `SetDelayed[lhs_, rhs_] := built-in`
@@ -76,9 +192,9 @@ pub(crate) fn register_set_delayed_builtin(context: &mut Context) {
                     let pattern = &arguments[&Symbol::new("lhs")];
                     let ground = &arguments[&Symbol::new("rhs")];
 
-                    declare_rule(pattern, ground, context);
+                    declare_rule(pattern, ground, context)?;
 
-                    EvalResult::Changed(Expr::from(sym!(Null)))
+                    Ok(EvalResult::Changed(Expr::from(sym!(Null))))
                 },
             },
         )
@@ -95,30 +211,353 @@ pub(crate) fn register_set_delayed_builtin(context: &mut Context) {
         .unwrap();
 }
 
-fn declare_rule(pattern: &Expr, ground: &Expr, context: &mut Context) {
+/// Registers the `TagSet` builtin symbol, which stores a definition against the given tag, for
+/// example `g /: f[g[x_]] = x` defines an up-value for `g`. As with `Set`, the right hand side is
+/// evaluated before the definition is stored.
+///
+/// - `Attributes[TagSet] = { ReadOnly, AttributesReadOnly, HoldAll, HoldSequences }`
+/// - `TagSet[tag_Symbol, lhs_, rhs_] := built-in`
+pub(crate) fn register_tag_set_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(TagSet),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("TagSet[tag_Symbol, lhs_, rhs_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let tag = arguments[&Symbol::new("tag")].try_symbol().unwrap();
+                    let pattern = &arguments[&Symbol::new("lhs")];
+                    let ground = evaluate(arguments[&Symbol::new("rhs")].clone(), context)?;
+
+                    if !declare_tagged_rule(tag, pattern, &ground, context)? {
+                        return Ok(EvalResult::Unchanged(expr));
+                    }
+
+                    Ok(EvalResult::Changed(ground))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(TagSet),
+            Attribute::ReadOnly
+                + Attribute::AttributesReadOnly
+                + Attribute::HoldAll
+                + Attribute::HoldSequences,
+        )
+        .unwrap();
+}
+
+/// Registers the `TagSetDelayed` builtin symbol, which stores a definition against the given tag
+/// with its right hand side unevaluated, for example `g /: f[g[x_]] := x`.
+///
+/// - `Attributes[TagSetDelayed] = { ReadOnly, AttributesReadOnly, HoldAll, HoldSequences }`
+/// - `TagSetDelayed[tag_Symbol, lhs_, rhs_] := built-in`
+pub(crate) fn register_tag_set_delayed_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(TagSetDelayed),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("TagSetDelayed[tag_Symbol, lhs_, rhs_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let tag = arguments[&Symbol::new("tag")].try_symbol().unwrap();
+                    let pattern = &arguments[&Symbol::new("lhs")];
+                    let ground = &arguments[&Symbol::new("rhs")];
+
+                    if !declare_tagged_rule(tag, pattern, ground, context)? {
+                        return Ok(EvalResult::Unchanged(expr));
+                    }
+
+                    Ok(EvalResult::Changed(Expr::from(sym!(Null))))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(TagSetDelayed),
+            Attribute::ReadOnly
+                + Attribute::AttributesReadOnly
+                + Attribute::HoldAll
+                + Attribute::HoldSequences,
+        )
+        .unwrap();
+}
+
+/// Registers the `Hold` builtin symbol.
+///
+/// - `Attributes[Hold] = { ReadOnly, AttributesReadOnly, HoldAll }`
+pub(crate) fn register_hold_builtin(context: &mut Context) {
+    context
+        .set_attributes(
+            &sym!(Hold),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
+/// Registers the `ReleaseHold` builtin symbol.
+///
+/// - `Attributes[ReleaseHold] = { ReadOnly, AttributesReadOnly }`
+/// - `ReleaseHold[expr_] := built-in`
+pub(crate) fn register_release_hold_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(ReleaseHold),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("ReleaseHold[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    let held = expr
+                        .try_normal_head(&sym!(Hold))
+                        .or_else(|| expr.try_normal_head(&sym!(HoldPattern)));
+
+                    match held {
+                        None => EvalResult::Changed(expr.clone()),
+                        Some(held) if held.len() == 1 => {
+                            EvalResult::Changed(held.element(0).unwrap().clone())
+                        }
+                        Some(held) => EvalResult::Changed(Expr::from(Normal::new(
+                            sym!(Sequence),
+                            held.elements(),
+                        ))),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(ReleaseHold),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Evaluate` builtin symbol.
+///
+/// Evaluation of `Evaluate[expr]` is forced even when it appears as a held argument, see
+/// `evaluate_step`.
+///
+/// - `Attributes[Evaluate] = { ReadOnly, AttributesReadOnly }`
+/// - `Evaluate[expr_] := built-in`
+pub(crate) fn register_evaluate_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Evaluate),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Evaluate[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    EvalResult::Changed(arguments[&Symbol::new("expr")].clone())
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Evaluate),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `HoldPattern` builtin symbol.
+///
+/// - `Attributes[HoldPattern] = { ReadOnly, AttributesReadOnly, HoldAll }`
+pub(crate) fn register_hold_pattern_builtin(context: &mut Context) {
+    context
+        .set_attributes(
+            &sym!(HoldPattern),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
+/// Declares a rule against the symbol the pattern is defined by, see [`definition_value_type`].
+/// Fails if the pattern has no such symbol, as for `5 = x`.
+fn declare_rule(pattern: &Expr, ground: &Expr, context: &mut Context) -> Result<(), EvalError> {
+    let Some(name) = strip_hold_pattern(pattern).name().cloned() else {
+        return Err(EvalError::InvalidAssignment(pattern.clone()));
+    };
+
+    declare_tagged_rule(&name, pattern, ground, context)?;
+    Ok(())
+}
+
+/// Declares a rule against the symbol `tag`. The rule is a value of `tag` itself if `tag` is the
+/// head of the pattern, such as `f[x_]`, otherwise an up-value if `tag` is the head of one of its
+/// elements, such as `f[g[x_]]` for `g`. Returns `false` if `tag` is in neither position, and fails
+/// if `tag` is read-only.
+fn declare_tagged_rule(
+    tag: &Symbol,
+    pattern: &Expr,
+    ground: &Expr,
+    context: &mut Context,
+) -> Result<bool, EvalError> {
+    let pattern = strip_hold_pattern(pattern);
     let (ground, condition) = extract_condition(ground);
 
+    let value_type = if pattern.name() == Some(tag) {
+        definition_value_type(pattern)
+    } else if pattern.try_normal().is_some_and(|normal| {
+        normal
+            .elements()
+            .iter()
+            .any(|elem| strip_hold_pattern(elem).name() == Some(tag))
+    }) {
+        Some(ValueType::UpValue)
+    } else {
+        None
+    };
+
+    let Some(value_type) = value_type else {
+        return Ok(false);
+    };
+
     let value = SymbolValue::Definitions {
         pattern: pattern.clone(),
         condition: condition.cloned(),
         ground: ground.clone(),
     };
 
-    let name = pattern.name().unwrap();
+    context
+        .set_value(tag, value_type, value)
+        .map_err(|_| EvalError::ReadOnly(tag.clone()))?;
+
+    Ok(true)
+}
 
+/// Returns the type of value a definition for `pattern` is stored as, for example `f` is an
+/// `OwnValue`, `f[x_]` is a `DownValue` and `f[x_][y_]` is a `SubValue`. Each is stored against
+/// the innermost head symbol, `f`.
+fn definition_value_type(pattern: &Expr) -> Option<ValueType> {
     match pattern.kind() {
-        ExprKind::Symbol(_) => {
-            context.set_value(name, ValueType::OwnValue, value).unwrap();
-        }
-        ExprKind::Normal(_) => {
-            context
-                .set_value(name, ValueType::DownValue, value)
-                .unwrap();
+        ExprKind::Symbol(_) => Some(ValueType::OwnValue),
+        ExprKind::Normal(normal) if normal.head().try_normal().is_some() => {
+            Some(ValueType::SubValue)
         }
-        _ => todo!(),
+        ExprKind::Normal(_) => Some(ValueType::DownValue),
+        _ => None,
     }
 }
 
+/// Registers the `Clear` builtin symbol.
+///
+/// - `Attributes[Clear] = { ReadOnly, AttributesReadOnly, HoldAll }`
+/// - `Clear[s_Symbol] := built-in`
+pub(crate) fn register_clear_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Clear),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Clear[s_Symbol]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let symbol = arguments[&Symbol::new("s")].try_symbol().unwrap();
+
+                    match context.clear_values(symbol) {
+                        Ok(()) => Ok(EvalResult::Changed(Expr::from(sym!(Null)))),
+                        Err(_) => Ok(EvalResult::Unchanged(expr)),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Clear),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
+/// Registers the `Unset` builtin symbol.
+///
+/// - `Attributes[Unset] = { ReadOnly, AttributesReadOnly, HoldFirst }`
+/// - `Unset[lhs_] := built-in`
+pub(crate) fn register_unset_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Unset),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Unset[lhs_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let pattern = strip_hold_pattern(&arguments[&Symbol::new("lhs")]);
+
+                    let Some(value_type) = definition_value_type(pattern) else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    let Some(name) = pattern.name() else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    match context.remove_value(name, value_type, pattern) {
+                        Ok(_) => Ok(EvalResult::Changed(Expr::from(sym!(Null)))),
+                        Err(_) => Ok(EvalResult::Unchanged(expr)),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Unset),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldFirst,
+        )
+        .unwrap();
+}
+
+/// Registers the `Remove` builtin symbol.
+///
+/// - `Attributes[Remove] = { ReadOnly, AttributesReadOnly, HoldAll }`
+/// - `Remove[s_Symbol] := built-in`
+pub(crate) fn register_remove_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Remove),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Remove[s_Symbol]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let symbol = arguments[&Symbol::new("s")].try_symbol().unwrap();
+
+                    match context.clear_symbol(symbol) {
+                        Ok(()) => Ok(EvalResult::Changed(Expr::from(sym!(Null)))),
+                        Err(_) => Ok(EvalResult::Unchanged(expr)),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Remove),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
 /// Registers the `Head` builtin symbol.
 ///
 /// - `Attributes[Head] = { ReadOnly, AttributesReadOnly }`
@@ -171,7 +610,7 @@ pub(crate) fn register_head_builtin(context: &mut Context) {
 
 /// Registers the `Plus` builtin symbol.
 ///
-/// - `Attributes[Plus] = { ReadOnly, AttributesReadOnly, Associative, Commutative }`
+/// - `Attributes[Plus] = { ReadOnly, AttributesReadOnly, Associative, Commutative, OneIdentity, Listable }`
 /// - `Plus[exprs___] := built-in`
 pub(crate) fn register_plus_builtin(context: &mut Context) {
     context
@@ -186,8 +625,17 @@ pub(crate) fn register_plus_builtin(context: &mut Context) {
                     let expr_elements =
                         try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
 
-                    let mut int_accumulator = BigInteger::new();
-                    let mut real_accumulator = BigFloat::new(DEFAULT_REAL_PRECISION);
+                    // Complex numbers are folded structurally, into a single `Complex[re, im]`.
+                    if let Some(new_elements) = fold_complex_sum(expr_elements) {
+                        return EvalResult::Changed(Expr::from(Normal::new(
+                            sym!(Plus),
+                            new_elements,
+                        )));
+                    }
+
+                    let mut exact_accumulator = BigRational::new();
+                    let precision = min_real_precision(expr_elements);
+                    let mut real_accumulator = BigFloat::new(precision);
                     let mut seen_real = false;
 
                     let mut new_elements = Vec::with_capacity(expr_elements.len());
@@ -195,7 +643,10 @@ pub(crate) fn register_plus_builtin(context: &mut Context) {
                     for expr in expr_elements {
                         match expr.kind() {
                             ExprKind::Integer(n) => {
-                                int_accumulator.add_assign(n);
+                                exact_accumulator.add_assign(n);
+                            }
+                            ExprKind::Rational(q) => {
+                                exact_accumulator.add_assign(q);
                             }
                             ExprKind::Real(r) => {
                                 real_accumulator.add_assign(r.as_float());
@@ -207,11 +658,13 @@ pub(crate) fn register_plus_builtin(context: &mut Context) {
                         }
                     }
 
+                    // Numbers are placed first, matching the canonical ordering of elements.
+                    // An exact zero is dropped, whereas an inexact zero is kept as `0. + x`.
                     if seen_real {
-                        real_accumulator.add_from(&int_accumulator);
-                        new_elements.push(Expr::from(real_accumulator));
-                    } else if int_accumulator != 0 {
-                        new_elements.push(Expr::from(int_accumulator));
+                        real_accumulator.add_from(&exact_accumulator);
+                        new_elements.insert(0, Expr::from(real_accumulator));
+                    } else if exact_accumulator != 0 {
+                        new_elements.insert(0, exact_number(exact_accumulator));
                     }
 
                     if new_elements.len() == 0 {
@@ -234,14 +687,16 @@ pub(crate) fn register_plus_builtin(context: &mut Context) {
             Attribute::ReadOnly
                 + Attribute::AttributesReadOnly
                 + Attribute::Associative
-                + Attribute::Commutative,
+                + Attribute::Commutative
+                + Attribute::OneIdentity
+                + Attribute::Listable,
         )
         .unwrap();
 }
 
 /// Registers the `Times` builtin symbol.
 ///
-/// - `Attributes[Times] = { ReadOnly, AttributesReadOnly, Associative, Commutative }`
+/// - `Attributes[Times] = { ReadOnly, AttributesReadOnly, Associative, Commutative, OneIdentity, Listable }`
 /// - `Times[exprs___] := built-in`
 pub(crate) fn register_times_builtin(context: &mut Context) {
     context
@@ -256,8 +711,17 @@ pub(crate) fn register_times_builtin(context: &mut Context) {
                     let expr_elements =
                         try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
 
-                    let mut int_accumulator = BigInteger::ONE.clone();
-                    let mut real_accumulator = BigFloat::with_val(DEFAULT_REAL_PRECISION, 1);
+                    // Complex numbers are folded structurally, into a single `Complex[re, im]`.
+                    if let Some(new_elements) = fold_complex_product(expr_elements) {
+                        return EvalResult::Changed(Expr::from(Normal::new(
+                            sym!(Times),
+                            new_elements,
+                        )));
+                    }
+
+                    let mut exact_accumulator = BigRational::from(1);
+                    let precision = min_real_precision(expr_elements);
+                    let mut real_accumulator = BigFloat::with_val(precision, 1);
                     let mut seen_real = false;
 
                     let mut new_elements = Vec::with_capacity(expr_elements.len());
@@ -265,7 +729,10 @@ pub(crate) fn register_times_builtin(context: &mut Context) {
                     for expr in expr_elements {
                         match expr.kind() {
                             ExprKind::Integer(n) => {
-                                int_accumulator.mul_assign(n);
+                                exact_accumulator.mul_assign(n);
+                            }
+                            ExprKind::Rational(q) => {
+                                exact_accumulator.mul_assign(q);
                             }
                             ExprKind::Real(r) => {
                                 real_accumulator.mul_assign(r.as_float());
@@ -277,11 +744,25 @@ pub(crate) fn register_times_builtin(context: &mut Context) {
                         }
                     }
 
+                    let mut new_elements = combine_like_bases(new_elements);
+
+                    // An exact zero factor short-circuits the product, unless it is combined
+                    // with a real or a reciprocal of zero which would make the result
+                    // indeterminate.
+                    if exact_accumulator == 0
+                        && real_accumulator.is_finite()
+                        && !new_elements.iter().any(is_zero_reciprocal)
+                    {
+                        return EvalResult::Changed(Expr::from(BigInteger::new()));
+                    }
+
+                    // Numbers are placed first, matching the canonical ordering of elements.
+                    // An exact one is dropped, whereas an inexact one is kept as `1. x`.
                     if seen_real {
-                        real_accumulator.mul_assign(&int_accumulator);
-                        new_elements.push(Expr::from(real_accumulator));
-                    } else if int_accumulator != 0 {
-                        new_elements.push(Expr::from(int_accumulator));
+                        real_accumulator.mul_assign(&exact_accumulator);
+                        new_elements.insert(0, Expr::from(real_accumulator));
+                    } else if exact_accumulator != 1 {
+                        new_elements.insert(0, exact_number(exact_accumulator));
                     }
 
                     if new_elements.len() == 0 {
@@ -304,11 +785,47 @@ pub(crate) fn register_times_builtin(context: &mut Context) {
             Attribute::ReadOnly
                 + Attribute::AttributesReadOnly
                 + Attribute::Associative
-                + Attribute::Commutative,
+                + Attribute::Commutative
+                + Attribute::OneIdentity
+                + Attribute::Listable,
         )
         .unwrap();
 }
 
+/// Combines factors which share a base by summing their exponents, for example `x^2 x^3` into
+/// `x^5` and `x x` into `x^2`. Factors keep the position of the first factor with their base.
+fn combine_like_bases(factors: Vec<Expr>) -> Vec<Expr> {
+    let mut powers: Vec<(Expr, Expr)> = Vec::with_capacity(factors.len());
+
+    for factor in factors {
+        let (base, exponent) = match factor.try_normal_head(&sym!(Power)) {
+            Some(power) if power.len() == 2 => (
+                power.element(0).unwrap().clone(),
+                power.element(1).unwrap().clone(),
+            ),
+            _ => (factor, Expr::from(BigInteger::ONE.clone())),
+        };
+
+        match powers.iter_mut().find(|(existing, _)| *existing == base) {
+            None => powers.push((base, exponent)),
+            Some((_, existing)) => {
+                *existing = match (existing.try_integer(), exponent.try_integer()) {
+                    (Some(a), Some(b)) => Expr::from(BigInteger::from(a + b)),
+                    _ => Expr::from(Normal::new(sym!(Plus), vec![existing.clone(), exponent])),
+                };
+            }
+        }
+    }
+
+    powers
+        .into_iter()
+        .map(|(base, exponent)| match exponent.try_integer() {
+            Some(e) if *e == 1 => base,
+            _ => Expr::from(Normal::new(sym!(Power), vec![base, exponent])),
+        })
+        .collect()
+}
+
 /// Registers the `Subtract` builtin symbol.
 ///
 /// - `Attributes[Subtract] = { ReadOnly, AttributesReadOnly }`
@@ -347,3 +864,1305 @@ pub(crate) fn register_subtract_builtin(context: &mut Context) {
         )
         .unwrap();
 }
+
+/// Registers the `Divide` builtin symbol.
+///
+/// - `Attributes[Divide] = { ReadOnly, AttributesReadOnly }`
+/// - `Divide[lhs_, rhs_] := built-in`
+pub(crate) fn register_divide_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Divide),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Divide[lhs_, rhs_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let lhs = &arguments[&Symbol::new("lhs")];
+                    let rhs = &arguments[&Symbol::new("rhs")];
+
+                    // Division of exact numbers is performed directly, producing a rational.
+                    if let (Some(lhs), Some(rhs)) = (try_exact(lhs), try_exact(rhs))
+                        && rhs != 0
+                    {
+                        return EvalResult::Changed(exact_number(lhs / rhs));
+                    }
+
+                    EvalResult::Changed(Expr::from(Normal::new(
+                        sym!(Times),
+                        vec![
+                            lhs.clone(),
+                            Expr::from(Normal::new(
+                                sym!(Power),
+                                vec![rhs.clone(), Expr::from(BigInteger::NEG_ONE.clone())],
+                            )),
+                        ],
+                    )))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Divide),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Power` builtin symbol.
+///
+/// - `Attributes[Power] = { ReadOnly, AttributesReadOnly }`
+/// - `Power[base_, exp_] := built-in`
+pub(crate) fn register_power_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Power),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Power[base_, exp_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let base = &arguments[&Symbol::new("base")];
+                    let exp = &arguments[&Symbol::new("exp")];
+
+                    // Identities which hold irrespective of the base.
+                    if let Some(n) = exp.try_integer() {
+                        if n.is_zero() {
+                            return EvalResult::Changed(Expr::from(BigInteger::ONE.clone()));
+                        }
+
+                        if *n == 1 {
+                            return EvalResult::Changed(base.clone());
+                        }
+                    }
+
+                    if base.try_integer().is_some_and(|b| *b == 1) {
+                        return EvalResult::Changed(base.clone());
+                    }
+
+                    if let Some(result) = complex_power(base, exp) {
+                        return EvalResult::Changed(result);
+                    }
+
+                    // Real results take the precision of the least precise real operand.
+                    let precision = min_real_precision(&[base.clone(), exp.clone()]);
+
+                    let result = match (base.kind(), exp.kind()) {
+                        (ExprKind::Integer(b), _) if b.is_zero() && is_positive(exp) => {
+                            Expr::from(BigInteger::new())
+                        }
+
+                        // Negative exponents produce the exact reciprocal, which for a zero base
+                        // is undefined so is left as-is.
+                        (ExprKind::Integer(_) | ExprKind::Rational(_), ExprKind::Integer(e)) => {
+                            let b = try_exact(base).unwrap();
+
+                            match e.to_i32() {
+                                Some(e) if e >= 0 || b != 0 => exact_number(b.pow(e)),
+                                _ => return EvalResult::Unchanged(expr),
+                            }
+                        }
+
+                        (ExprKind::Integer(_) | ExprKind::Rational(_), ExprKind::Real(e)) => {
+                            let b = BigFloat::with_val(precision, &try_exact(base).unwrap());
+                            Expr::from(b.pow(e.as_float()))
+                        }
+
+                        // Rational exponents are only evaluated when the result is exact, for
+                        // example `4^(1/2)` is `2` whereas `2^(1/2)` is left as-is.
+                        (ExprKind::Integer(_) | ExprKind::Rational(_), ExprKind::Rational(e)) => {
+                            match exact_root(try_exact(base).unwrap(), e) {
+                                Some(result) => result,
+                                None => return EvalResult::Unchanged(expr),
+                            }
+                        }
+
+                        (ExprKind::Real(b), ExprKind::Integer(e)) => {
+                            Expr::from(BigFloat::with_val(precision, b.as_float().pow(e)))
+                        }
+
+                        (ExprKind::Real(b), ExprKind::Rational(e)) => {
+                            let e = BigFloat::with_val(precision, e);
+                            Expr::from(BigFloat::with_val(precision, b.as_float().pow(&e)))
+                        }
+
+                        (ExprKind::Real(b), ExprKind::Real(e)) => Expr::from(BigFloat::with_val(
+                            precision,
+                            b.as_float().pow(e.as_float()),
+                        )),
+
+                        _ => return EvalResult::Unchanged(expr),
+                    };
+
+                    // Real powers of negative bases are complex, which cannot yet be represented.
+                    match result.kind() {
+                        ExprKind::Real(r) if r.as_float().is_nan() => EvalResult::Unchanged(expr),
+                        _ => EvalResult::Changed(result),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Power),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Raises a non-negative exact number to a rational power, so long as the result is also exact.
+fn exact_root(base: BigRational, exp: &BigRational) -> Option<Expr> {
+    if base.is_negative() {
+        return None;
+    }
+
+    let n = exp.numer().to_i32()?;
+    let q = exp.denom().to_u32()?;
+
+    let (numer, denom) = base.into_numer_denom();
+    let root = |x: BigInteger| {
+        let root = BigInteger::from(x.root_ref(q));
+        (root.clone().pow(q) == x).then_some(root)
+    };
+
+    let root = BigRational::from((root(numer)?, root(denom)?));
+    if n < 0 && root == 0 {
+        return None;
+    }
+
+    Some(exact_number(root.pow(n)))
+}
+
+/// Evaluates powers which produce or operate on complex numbers. Powers of a complex number
+/// with an integer exponent of magnitude at most 255 are expanded into products. The square
+/// root of a negative number is taken as the square root of its magnitude multiplied by `I`.
+fn complex_power(base: &Expr, exp: &Expr) -> Option<Expr> {
+    if let Some((re, im)) = try_complex(base) {
+        let e = exp.try_integer()?;
+
+        // 1 / (a + b i) = (a - b i) / (a^2 + b^2)
+        let base = if e.is_negative() {
+            let square = |x: &Expr| {
+                Expr::from(Normal::new(
+                    sym!(Power),
+                    vec![x.clone(), Expr::from(BigInteger::from(2))],
+                ))
+            };
+
+            let magnitude = Expr::from(Normal::new(
+                sym!(Power),
+                vec![
+                    Expr::from(Normal::new(sym!(Plus), vec![square(re), square(im)])),
+                    Expr::from(BigInteger::NEG_ONE.clone()),
+                ],
+            ));
+
+            Expr::from(Normal::new(
+                sym!(Complex),
+                vec![
+                    Expr::from(Normal::new(
+                        sym!(Times),
+                        vec![re.clone(), magnitude.clone()],
+                    )),
+                    Expr::from(Normal::new(
+                        sym!(Times),
+                        vec![
+                            Expr::from(BigInteger::NEG_ONE.clone()),
+                            im.clone(),
+                            magnitude,
+                        ],
+                    )),
+                ],
+            ))
+        } else {
+            base.clone()
+        };
+
+        let copies = e.clone().abs().to_u8()?;
+        return Some(Expr::from(Normal::new(
+            sym!(Times),
+            vec![base; copies as usize],
+        )));
+    }
+
+    let b = try_exact(base)?;
+    if b.is_negative()
+        && exp
+            .try_rational()
+            .is_some_and(|e| *e == BigRational::from((1, 2)))
+    {
+        return Some(Expr::from(Normal::new(
+            sym!(Times),
+            vec![
+                parse!("Complex[0, 1]"),
+                Expr::from(Normal::new(
+                    sym!(Power),
+                    vec![exact_number(-b), exp.clone()],
+                )),
+            ],
+        )));
+    }
+
+    None
+}
+
+/// Registers the `Factorial` builtin symbol.
+///
+/// - `Attributes[Factorial] = { ReadOnly, AttributesReadOnly }`
+/// - `Factorial[n_] := built-in`
+pub(crate) fn register_factorial_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Factorial),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Factorial[n_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let n = &arguments[&Symbol::new("n")];
+
+                    match n.try_integer().and_then(|n| n.to_u32()) {
+                        Some(n) => EvalResult::Changed(Expr::from(BigInteger::from(
+                            BigInteger::factorial(n),
+                        ))),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Factorial),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Map` builtin symbol.
+///
+/// - `Attributes[Map] = { ReadOnly, AttributesReadOnly }`
+/// - `Map[f_, expr_] := built-in`
+pub(crate) fn register_map_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Map),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Map[f_, expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let f = &arguments[&Symbol::new("f")];
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    match expr.try_normal() {
+                        Some(normal) => {
+                            let new_elements = normal
+                                .elements()
+                                .iter()
+                                .map(|element| {
+                                    Expr::from(Normal::new(f.clone(), vec![element.clone()]))
+                                })
+                                .collect::<Vec<_>>();
+
+                            EvalResult::Changed(Expr::from(Normal::new(
+                                normal.head().clone(),
+                                new_elements,
+                            )))
+                        }
+                        None => EvalResult::Changed(expr.clone()),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Map),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Apply` builtin symbol.
+///
+/// - `Attributes[Apply] = { ReadOnly, AttributesReadOnly }`
+/// - `Apply[f_, expr_] := built-in`
+pub(crate) fn register_apply_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Apply),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Apply[f_, expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let f = &arguments[&Symbol::new("f")];
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    match expr.try_normal() {
+                        Some(normal) => EvalResult::Changed(Expr::from(Normal::new(
+                            f.clone(),
+                            normal.elements(),
+                        ))),
+                        None => EvalResult::Changed(expr.clone()),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Apply),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Nest` builtin symbol, which applies `f` to `expr` `n` times, evaluating the
+/// result after each application.
+///
+/// - `Attributes[Nest] = { ReadOnly, AttributesReadOnly }`
+/// - `Nest[f_, expr_, n_Integer] := built-in`
+pub(crate) fn register_nest_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Nest),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Nest[f_, expr_, n_Integer]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let f = &arguments[&Symbol::new("f")];
+                    let n = arguments[&Symbol::new("n")].try_integer().unwrap();
+
+                    let Some(n) = n.to_usize() else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    let mut current = arguments[&Symbol::new("expr")].clone();
+                    for _ in 0..n {
+                        current =
+                            evaluate(Expr::from(Normal::new(f.clone(), vec![current])), context)?;
+                    }
+
+                    Ok(EvalResult::Changed(current))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Nest),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `NestList` builtin symbol, which lists `expr` followed by the result of each of
+/// `n` applications of `f`, as for `Nest`.
+///
+/// - `Attributes[NestList] = { ReadOnly, AttributesReadOnly }`
+/// - `NestList[f_, expr_, n_Integer] := built-in`
+pub(crate) fn register_nest_list_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(NestList),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("NestList[f_, expr_, n_Integer]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let f = &arguments[&Symbol::new("f")];
+                    let n = arguments[&Symbol::new("n")].try_integer().unwrap();
+
+                    let Some(n) = n.to_usize() else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    let mut results = vec![arguments[&Symbol::new("expr")].clone()];
+                    for _ in 0..n {
+                        let previous = results.last().unwrap().clone();
+                        results.push(evaluate(
+                            Expr::from(Normal::new(f.clone(), vec![previous])),
+                            context,
+                        )?);
+                    }
+
+                    Ok(EvalResult::Changed(Expr::from(Normal::new(
+                        sym!(List),
+                        results,
+                    ))))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(NestList),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Function` builtin symbol. Applications of pure functions are handled directly
+/// during evaluation.
+///
+/// - `Attributes[Function] = { ReadOnly, AttributesReadOnly, HoldAll }`
+pub(crate) fn register_function_builtin(context: &mut Context) {
+    context
+        .set_attributes(
+            &sym!(Function),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
+/// Registers the `If` builtin symbol.
+///
+/// - `Attributes[If] = { ReadOnly, AttributesReadOnly, HoldRest }`
+/// - `If[cond_, t_, f_] := built-in`
+/// - `If[cond_, t_] := built-in`
+pub(crate) fn register_if_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(If),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("If[cond_, t_, f_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let cond = &arguments[&Symbol::new("cond")];
+                    let t = &arguments[&Symbol::new("t")];
+                    let f = &arguments[&Symbol::new("f")];
+
+                    match cond.try_symbol() {
+                        Some(s) if *s == sym!(True) => EvalResult::Changed(t.clone()),
+                        Some(s) if *s == sym!(False) => EvalResult::Changed(f.clone()),
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_value(
+            &sym!(If),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("If[cond_, t_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let cond = &arguments[&Symbol::new("cond")];
+                    let t = &arguments[&Symbol::new("t")];
+
+                    match cond.try_symbol() {
+                        Some(s) if *s == sym!(True) => EvalResult::Changed(t.clone()),
+                        Some(s) if *s == sym!(False) => EvalResult::Changed(Expr::from(sym!(Null))),
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(If),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldRest,
+        )
+        .unwrap();
+}
+
+/// Registers the `CompoundExpression` builtin symbol.
+///
+/// - `Attributes[CompoundExpression] = { ReadOnly, AttributesReadOnly, HoldAll }`
+/// - `CompoundExpression[exprs___] := built-in`
+pub(crate) fn register_compound_expression_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(CompoundExpression),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("CompoundExpression[exprs___]"),
+                condition: None,
+                built_in: |arguments, _, context| {
+                    let exprs = &arguments[&Symbol::new("exprs")];
+                    let expr_elements =
+                        try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+                    let Some((last, rest)) = expr_elements.split_last() else {
+                        return Ok(EvalResult::Changed(Expr::from(sym!(Null))));
+                    };
+
+                    // Every expression but the last is evaluated for its side effects only. The
+                    // last is left to the evaluator, as it is the result.
+                    for elem in rest {
+                        evaluate(elem.clone(), context)?;
+                    }
+
+                    Ok(EvalResult::Changed(last.clone()))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(CompoundExpression),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
+/// Registers the `SameQ` builtin symbol.
+///
+/// - `Attributes[SameQ] = { ReadOnly, AttributesReadOnly }`
+/// - `SameQ[exprs___] := built-in`
+pub(crate) fn register_same_q_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(SameQ),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("SameQ[exprs___]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let exprs = &arguments[&Symbol::new("exprs")];
+                    let expr_elements =
+                        try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+                    let same = expr_elements.windows(2).all(|pair| pair[0] == pair[1]);
+
+                    EvalResult::Changed(Expr::from(bool_symbol(same)))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(SameQ),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `UnsameQ` builtin symbol.
+///
+/// - `Attributes[UnsameQ] = { ReadOnly, AttributesReadOnly }`
+/// - `UnsameQ[exprs___] := built-in`
+pub(crate) fn register_unsame_q_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(UnsameQ),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("UnsameQ[exprs___]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let exprs = &arguments[&Symbol::new("exprs")];
+                    let expr_elements =
+                        try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+                    // Every argument must be distinct from every other argument.
+                    let unsame = expr_elements
+                        .iter()
+                        .enumerate()
+                        .all(|(idx, lhs)| expr_elements[idx + 1..].iter().all(|rhs| lhs != rhs));
+
+                    EvalResult::Changed(Expr::from(bool_symbol(unsame)))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(UnsameQ),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Length` builtin symbol.
+///
+/// - `Attributes[Length] = { ReadOnly, AttributesReadOnly }`
+/// - `Length[expr_] := built-in`
+pub(crate) fn register_length_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Length),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Length[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    let len = match expr.try_normal() {
+                        Some(normal) => normal.len(),
+                        None => 0,
+                    };
+
+                    EvalResult::Changed(Expr::from(BigInteger::from(len)))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Length),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+fn bool_symbol(value: bool) -> Symbol {
+    if value { sym!(True) } else { sym!(False) }
+}
+
+/// Returns the lowest precision of the reals within `exprs`, as the result of arithmetic between
+/// reals can be no more precise than its least precise operand. Defaults to
+/// `DEFAULT_REAL_PRECISION` when there are no reals.
+fn min_real_precision(exprs: &[Expr]) -> u32 {
+    exprs
+        .iter()
+        .filter_map(|expr| expr.try_real())
+        .map(real_precision)
+        .min()
+        .unwrap_or(DEFAULT_REAL_PRECISION)
+}
+
+/// Returns the value of an exact number, either an integer or a rational.
+fn try_exact(expr: &Expr) -> Option<BigRational> {
+    match expr.kind() {
+        ExprKind::Integer(n) => Some(BigRational::from(n)),
+        ExprKind::Rational(q) => Some(q.clone()),
+        _ => None,
+    }
+}
+
+/// Converts an exact rational value into an expression, producing an integer when the value has
+/// a denominator of one.
+fn exact_number(value: BigRational) -> Expr {
+    if *value.denom() == 1 {
+        Expr::from(value.into_numer_denom().0)
+    } else {
+        Expr::from(value)
+    }
+}
+
+fn is_positive(expr: &Expr) -> bool {
+    match expr.kind() {
+        ExprKind::Integer(n) => n.is_positive(),
+        ExprKind::Rational(q) => q.is_positive(),
+        ExprKind::Real(r) => r.as_float().is_sign_positive() && !r.as_float().is_zero(),
+        _ => false,
+    }
+}
+
+/// Whether the expression is a negative exact power of an exact zero, such as `0^-1`, which
+/// `Power` leaves unevaluated.
+fn is_zero_reciprocal(expr: &Expr) -> bool {
+    let Some(power) = expr.try_normal_head(&sym!(Power)) else {
+        return false;
+    };
+
+    if power.len() != 2
+        || !power
+            .element(0)
+            .unwrap()
+            .try_integer()
+            .is_some_and(|b| b.is_zero())
+    {
+        return false;
+    }
+
+    match power.element(1).unwrap().kind() {
+        ExprKind::Integer(n) => n.is_negative(),
+        ExprKind::Rational(q) => q.is_negative(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod hold_pattern {
+        evaluate_test!(unevaluated, "HoldPattern[1 + 1]", "HoldPattern[1 + 1]");
+        evaluate_test!(
+            definition,
+            ["HoldPattern[f[x_, 0]] := x"],
+            "{f[a, 0], f[a, 1]}",
+            "{a, f[a, 1]}"
+        );
+        evaluate_test!(own_value, ["HoldPattern[x] = 5"], "x", "5");
+    }
+
+    mod plus {
+        use crate::{Context, evaluate, parse_str, real_precision};
+
+        fn precision_of(input: &str) -> u32 {
+            let mut context = Context::new_global_context();
+            let result = evaluate(parse_str(input).unwrap(), &mut context).unwrap();
+
+            real_precision(result.try_real().unwrap())
+        }
+
+        #[test]
+        fn precise_reals() {
+            assert_eq!(precision_of("Plus[N[1, 30], N[2, 30]]"), 100);
+        }
+
+        #[test]
+        fn mixed_precision_reals() {
+            assert_eq!(precision_of("Plus[N[1, 30], 1.5]"), 53);
+            assert_eq!(precision_of("Plus[N[1, 30], N[2, 20]]"), 67);
+        }
+
+        #[test]
+        fn precise_real_and_integer() {
+            assert_eq!(precision_of("Plus[N[1, 30], 2]"), 100);
+        }
+
+        evaluate_test!(integers, "Plus[1, 2, 3]", "6");
+        evaluate_test!(reals, "Plus[1.5, 2.5]", "4.0");
+        evaluate_test!(mixed, "Plus[1, 2.5]", "3.5");
+        evaluate_test!(negative_real, "Plus[-1.5, 1]", "-0.5");
+        evaluate_test!(negative_reals, "Plus[-1.5, -2.5, x]", "Plus[-4.0, x]");
+        evaluate_test!(symbolic, "Plus[1, x, 2]", "Plus[3, x]");
+        evaluate_test!(exact_zero, "Plus[0, x]", "x");
+        evaluate_test!(exact_zero_sum, "Plus[2, -2, x]", "x");
+        evaluate_test!(inexact_zero, "Plus[0.0, x]", "Plus[0.0, x]");
+        evaluate_test!(inexact_zero_sum, "Plus[1.5, -1.5, x]", "Plus[0.0, x]");
+        evaluate_test!(empty, "Plus[]", "0");
+        evaluate_test!(infix_operator, "1 + 2", "3");
+        evaluate_test!(chained_with_subtract, "1 - 2 + 3", "2");
+        evaluate_test!(
+            rationals,
+            "SameQ[Plus[Rational[1, 3], Rational[1, 4]], Rational[7, 12]]",
+            "True"
+        );
+        evaluate_test!(
+            integral_rationals,
+            "Plus[Rational[1, 2], Rational[1, 2]]",
+            "1"
+        );
+        evaluate_test!(
+            integer_and_rational,
+            "SameQ[Plus[1, Rational[1, 2]], Rational[3, 2]]",
+            "True"
+        );
+        evaluate_test!(real_and_rational, "Plus[0.5, Rational[1, 4]]", "0.75");
+        evaluate_test!(lists, "{1, 2} + {3, x}", "{4, Plus[2, x]}");
+        evaluate_test!(list_and_scalar, "{1, 2} + 1", "{2, 3}");
+    }
+
+    mod times {
+        use crate::{Context, evaluate, parse_str, real_precision};
+
+        fn precision_of(input: &str) -> u32 {
+            let mut context = Context::new_global_context();
+            let result = evaluate(parse_str(input).unwrap(), &mut context).unwrap();
+
+            real_precision(result.try_real().unwrap())
+        }
+
+        #[test]
+        fn precise_reals() {
+            assert_eq!(precision_of("Times[N[2, 30], N[3, 30]]"), 100);
+        }
+
+        #[test]
+        fn mixed_precision_reals() {
+            assert_eq!(precision_of("Times[N[2, 30], 1.5]"), 53);
+            assert_eq!(precision_of("Times[N[2, 30], N[3, 20]]"), 67);
+        }
+
+        #[test]
+        fn precise_real_and_rational() {
+            assert_eq!(precision_of("Times[N[2, 30], 1/3]"), 100);
+        }
+
+        evaluate_test!(integers, "Times[2, 3, 4]", "24");
+        evaluate_test!(reals, "Times[1.5, 2.0]", "3.0");
+        evaluate_test!(mixed, "Times[2, 1.5]", "3.0");
+        evaluate_test!(symbolic, "Times[2, 3, x]", "Times[6, x]");
+        evaluate_test!(only_symbolic, "Times[a, b]", "Times[a, b]");
+        evaluate_test!(exact_one, "Times[1, x]", "x");
+        evaluate_test!(inexact_one, "Times[1.0, x]", "Times[1.0, x]");
+        evaluate_test!(exact_zero, "Times[0, x]", "0");
+        evaluate_test!(exact_zero_with_real, "Times[0, 1.5, x]", "0");
+        evaluate_test!(zero_divided_by_zero, "0/0", "Times[0, Power[0, -1]]");
+        evaluate_test!(
+            divide_zero_by_zero,
+            "Divide[0, 0]",
+            "Times[0, Power[0, -1]]"
+        );
+        evaluate_test!(
+            zero_times_reciprocal_of_zero,
+            "Times[0, x, Power[0, -2]]",
+            "Times[0, x, Power[0, -2]]"
+        );
+        evaluate_test!(
+            zero_times_root_reciprocal_of_zero,
+            "SameQ[Times[0, Power[0, -1/2]], 0]",
+            "False"
+        );
+        evaluate_test!(
+            replaced_zero_divisor,
+            "x/0 /. x -> 0",
+            "Times[0, Power[0, -1]]"
+        );
+        evaluate_test!(lists, "{1, 2} * {3, x}", "{3, Times[2, x]}");
+        evaluate_test!(list_and_scalar, "{1, 2} * 2", "{2, 4}");
+        evaluate_test!(inexact_zero, "Times[0.0, x]", "Times[0.0, x]");
+        evaluate_test!(empty, "Times[]", "1");
+        evaluate_test!(infix_operator, "2 * 3", "6");
+        evaluate_test!(
+            rationals,
+            "SameQ[Times[Rational[1, 2], Rational[2, 3]], Rational[1, 3]]",
+            "True"
+        );
+        evaluate_test!(integral_rationals, "Times[Rational[3, 2], 4]", "6");
+        evaluate_test!(real_and_rational, "Times[0.5, Rational[1, 4]]", "0.125");
+        evaluate_test!(rational_zero, "Times[0, Rational[1, 2], x]", "0");
+    }
+
+    mod times_like_bases {
+        evaluate_test!(repeated_factor, "Times[x, x]", "Power[x, 2]");
+        evaluate_test!(powers, "Times[Power[x, 2], Power[x, 3]]", "Power[x, 5]");
+        evaluate_test!(power_and_factor, "Times[x, Power[x, 2]]", "Power[x, 3]");
+        evaluate_test!(
+            symbolic_exponents,
+            "Times[Power[x, a], Power[x, b]]",
+            "Power[x, a + b]"
+        );
+        evaluate_test!(cancelling, "Times[x, Power[x, -1]]", "1");
+        evaluate_test!(distinct_bases, "Times[x, y, x]", "Times[y, Power[x, 2]]");
+        evaluate_test!(with_number, "Times[2, x, 3, x]", "Times[6, Power[x, 2]]");
+        evaluate_test!(
+            infix_operator,
+            "x * y * x^2 * y",
+            "Times[Power[x, 3], Power[y, 2]]"
+        );
+    }
+
+    mod divide {
+        evaluate_step_test!(integer_operands, "Divide[6, 2]", "3");
+        evaluate_test!(
+            rational_result,
+            "SameQ[Divide[6, 4], Rational[3, 2]]",
+            "True"
+        );
+        evaluate_test!(
+            rational_operands,
+            "SameQ[Divide[Rational[1, 2], 3], Rational[1, 6]]",
+            "True"
+        );
+        evaluate_test!(sum_of_quotients, "SameQ[1/3 + 1/6, 1/2]", "True");
+        evaluate_step_test!(zero_denominator, "Divide[1, 0]", "Times[1, Power[0, -1]]");
+        evaluate_step_test!(
+            real_operands,
+            "Divide[1.5, 0.5]",
+            "Times[1.5, Power[0.5, -1]]"
+        );
+        evaluate_step_test!(symbolic_operands, "Divide[a, b]", "Times[a, Power[b, -1]]");
+        evaluate_step_test!(infix_operator, "a / b", "Times[a, Power[b, -1]]");
+    }
+
+    mod power {
+        use crate::{Context, evaluate, parse_str, real_precision};
+
+        fn precision_of(input: &str) -> u32 {
+            let mut context = Context::new_global_context();
+            let result = evaluate(parse_str(input).unwrap(), &mut context).unwrap();
+
+            real_precision(result.try_real().unwrap())
+        }
+
+        #[test]
+        fn precise_real_base() {
+            assert_eq!(precision_of("Power[N[2, 50], 2]"), 167);
+            assert_eq!(precision_of("Power[N[2, 30], 1/2]"), 100);
+            assert_eq!(precision_of("Power[N[2, 30], N[3, 30]]"), 100);
+        }
+
+        #[test]
+        fn precise_real_exponent() {
+            assert_eq!(precision_of("Power[2, N[1/2, 30]]"), 100);
+        }
+
+        #[test]
+        fn mixed_precision_reals() {
+            assert_eq!(precision_of("Power[N[2, 50], 1.5]"), 53);
+            assert_eq!(precision_of("Power[N[2, 30], N[3, 20]]"), 67);
+        }
+
+        evaluate_test!(integer_power, "Power[2, 10]", "1024");
+        evaluate_test!(real_integer_power, "Power[2.0, 3]", "8.0");
+        evaluate_test!(real_real_power, "Power[4.0, 0.5]", "2.0");
+        evaluate_test!(integer_real_power, "Power[4, 0.5]", "2.0");
+        evaluate_test!(exponent_zero, "Power[x, 0]", "1");
+        evaluate_test!(exponent_one, "Power[x, 1]", "x");
+        evaluate_test!(zero_base, "Power[0, 5]", "0");
+        evaluate_test!(
+            negative_exponent,
+            "SameQ[Power[2, -1], Rational[1, 2]]",
+            "True"
+        );
+        evaluate_test!(rational_reciprocal, "SameQ[2^-2, 1/4]", "True");
+        evaluate_test!(
+            rational_base,
+            "SameQ[Power[Rational[2, 3], 2], 4/9]",
+            "True"
+        );
+        evaluate_test!(
+            rational_base_negative_exponent,
+            "Power[Rational[1, 2], -3]",
+            "8"
+        );
+        evaluate_test!(zero_base_negative_exponent, "Power[0, -1]", "Power[0, -1]");
+        evaluate_test!(
+            rational_base_real_exponent,
+            "Power[Rational[1, 4], 0.5]",
+            "0.5"
+        );
+        evaluate_test!(symbolic, "Power[x, y]", "Power[x, y]");
+        evaluate_test!(infix_operator, "2 ^ 10", "1024");
+        evaluate_test!(right_associative, "2 ^ 3 ^ 2", "512");
+    }
+
+    mod factorial {
+        evaluate_test!(positive_integer, "Factorial[5]", "120");
+        evaluate_test!(zero, "Factorial[0]", "1");
+        evaluate_test!(negative_integer, "Factorial[-3]", "Factorial[-3]");
+        evaluate_test!(symbolic, "Factorial[x]", "Factorial[x]");
+        evaluate_test!(postfix_operator, "5!", "120");
+    }
+
+    mod map {
+        evaluate_test!(list, "Map[f, {a, b, c}]", "{f[a], f[b], f[c]}");
+        evaluate_test!(preserves_head, "Map[f, g[a, b]]", "g[f[a], f[b]]");
+        evaluate_test!(empty, "Map[f, g[]]", "g[]");
+        evaluate_test!(atom, "Map[f, 3]", "3");
+        evaluate_test!(infix_operator, "f /@ {a, b, c}", "{f[a], f[b], f[c]}");
+    }
+
+    mod apply {
+        evaluate_test!(replaces_head, "Apply[f, g[a, b, c]]", "f[a, b, c]");
+        evaluate_test!(atom, "Apply[f, 3]", "3");
+        evaluate_test!(plus_of_list, "Apply[Plus, {1, 2, 3}]", "6");
+        evaluate_test!(infix_operator, "Plus @@ {1, 2, 3}", "6");
+    }
+
+    mod nest {
+        evaluate_test!(symbolic, "Nest[f, x, 3]", "f[f[f[x]]]");
+        evaluate_test!(zero_times, "Nest[f, x, 0]", "x");
+        evaluate_test!(evaluated, "Nest[#1 * 2 &, 1, 10]", "1024");
+        evaluate_test!(defined_function, ["g[x_] := x + 1"], "Nest[g, 0, 5]", "5");
+        evaluate_test!(negative, "Nest[f, x, -1]", "Nest[f, x, -1]");
+        evaluate_test!(not_an_integer, "Nest[f, x, n]", "Nest[f, x, n]");
+    }
+
+    mod nest_list {
+        evaluate_test!(symbolic, "NestList[f, x, 2]", "{x, f[x], f[f[x]]}");
+        evaluate_test!(zero_times, "NestList[f, x, 0]", "{x}");
+        evaluate_test!(evaluated, "NestList[#1 + 1 &, 0, 3]", "{0, 1, 2, 3}");
+        evaluate_test!(negative, "NestList[f, x, -1]", "NestList[f, x, -1]");
+    }
+
+    mod set {
+        use crate::{Context, Expr, Symbol, SymbolValue, ValueType, evaluate, parse_str};
+
+        fn stored_value(symbol: &str, context: &Context) -> Expr {
+            let values = context
+                .get_values(&Symbol::new(symbol), ValueType::OwnValue)
+                .unwrap();
+
+            match &values[0] {
+                SymbolValue::Definitions { ground, .. } => ground.clone(),
+                _ => unreachable!(),
+            }
+        }
+
+        evaluate_test!(returns_value, "a = 1 + 1", "2");
+        evaluate_test!(evaluated_once, ["x = 1", "a = x", "x = 2"], "a", "1");
+        evaluate_test!(self_referential, ["x = 1", "x = x + 1"], "x", "2");
+
+        #[test]
+        fn stores_evaluated() {
+            let mut context = Context::new_global_context();
+
+            evaluate(parse_str("a = 1 + 1").unwrap(), &mut context).unwrap();
+
+            assert_eq!(stored_value("a", &context), parse_str("2").unwrap());
+        }
+
+        #[test]
+        fn delayed_stores_unevaluated() {
+            let mut context = Context::new_global_context();
+
+            evaluate(parse_str("a := 1 + 1").unwrap(), &mut context).unwrap();
+
+            assert_eq!(stored_value("a", &context), parse_str("1 + 1").unwrap());
+        }
+    }
+
+    mod set_delayed {
+        evaluate_test!(returns_null, "a := 1 + 1", "Null");
+        evaluate_test!(evaluated_each_use, ["x = 1", "a := x", "x = 2"], "a", "2");
+    }
+
+    mod tag_set {
+        use crate::{Context, Symbol, ValueType, evaluate, parse_str};
+
+        evaluate_test!(up_value, ["g /: f[g[x_]] := x"], "f[g[1]]", "1");
+        evaluate_test!(
+            other_heads_unaffected,
+            ["g /: f[g[x_]] := x"],
+            "{f[h[1]], h[g[1]]}",
+            "{f[h[1]], h[g[1]]}"
+        );
+        evaluate_test!(symbol_element, ["g /: f[g] = 1"], "f[g]", "1");
+        evaluate_test!(down_value, ["f /: f[x_] := x + 1"], "f[1]", "2");
+        evaluate_test!(returns_value, "g /: f[g] = 1 + 1", "2");
+        evaluate_test!(delayed_returns_null, "g /: f[g] := 1", "Null");
+        evaluate_test!(
+            evaluated_once,
+            ["x = 1", "g /: f[g] = x", "x = 2"],
+            "f[g]",
+            "1"
+        );
+        evaluate_test!(
+            evaluated_each_use,
+            ["x = 1", "g /: f[g] := x", "x = 2"],
+            "f[g]",
+            "2"
+        );
+        evaluate_test!(
+            tag_not_found,
+            "h /: f[g[x_]] := x",
+            "TagSetDelayed[h, f[g[Pattern[x, Blank[]]]], x]"
+        );
+
+        #[test]
+        fn stored_as_up_value() {
+            let mut context = Context::new_global_context();
+
+            evaluate(parse_str("g /: f[g[x_]] := x").unwrap(), &mut context).unwrap();
+
+            let values = context
+                .get_values(&Symbol::new("g"), ValueType::UpValue)
+                .unwrap();
+
+            assert_eq!(values.len(), 1);
+            assert_eq!(values[0].pattern(), &parse_str("f[g[x_]]").unwrap());
+            assert!(
+                context
+                    .get_values(&Symbol::new("f"), ValueType::DownValue)
+                    .is_none_or(|values| values.is_empty())
+            );
+        }
+    }
+
+    mod assignment_errors {
+        use crate::{Context, EvalError, Expr, Symbol, evaluate, parse_str};
+
+        fn run(input: &str, context: &mut Context) -> Result<Expr, EvalError> {
+            evaluate(parse_str(input).unwrap(), context)
+        }
+
+        #[test]
+        fn literal() {
+            let mut context = Context::new_global_context();
+
+            assert_eq!(
+                run("5 = x", &mut context),
+                Err(EvalError::InvalidAssignment(parse_str("5").unwrap()))
+            );
+            assert_eq!(
+                run("\"a\" := x", &mut context),
+                Err(EvalError::InvalidAssignment(parse_str("\"a\"").unwrap()))
+            );
+        }
+
+        #[test]
+        fn read_only() {
+            let mut context = Context::new_global_context();
+
+            assert_eq!(
+                run("Plus[x_, y_] := 0", &mut context),
+                Err(EvalError::ReadOnly(Symbol::new("Plus")))
+            );
+            assert_eq!(
+                run("Plus /: Plus[a] = 1", &mut context),
+                Err(EvalError::ReadOnly(Symbol::new("Plus")))
+            );
+            assert_eq!(run("1 + 2", &mut context), Ok(parse_str("3").unwrap()));
+        }
+
+        #[test]
+        fn stops_evaluation() {
+            let mut context = Context::new_global_context();
+
+            assert!(run("x = 1; 5 = 2; x = 3", &mut context).is_err());
+            assert_eq!(run("x", &mut context), Ok(parse_str("1").unwrap()));
+        }
+    }
+
+    mod hold {
+        evaluate_test!(unevaluated, "Hold[1 + 1]", "Hold[1 + 1]");
+        evaluate_test!(several, "Hold[1 + 1, 2 + 2]", "Hold[1 + 1, 2 + 2]");
+        evaluate_test!(forced, "Hold[Evaluate[1 + 1], 2 + 2]", "Hold[2, 2 + 2]");
+        evaluate_test!(forced_symbol, ["x = 3"], "Hold[Evaluate[x]]", "Hold[3]");
+    }
+
+    mod release_hold {
+        evaluate_test!(hold, "ReleaseHold[Hold[1 + 1]]", "2");
+        evaluate_test!(several, "ReleaseHold[Hold[1 + 1, 2 + 2]]", "Sequence[2, 4]");
+        evaluate_test!(unheld, "ReleaseHold[1 + 1]", "2");
+        evaluate_test!(nested, "ReleaseHold[Hold[Hold[1 + 1]]]", "Hold[1 + 1]");
+    }
+
+    mod evaluate {
+        evaluate_test!(unheld, "Evaluate[1 + 1]", "2");
+        evaluate_test!(
+            held_argument,
+            ["f[x_] := Hold[x]", "SetAttributes[f, HoldAll]"],
+            "f[Evaluate[1 + 1]]",
+            "Hold[2]"
+        );
+    }
+
+    mod clear {
+        use crate::{Attribute, Context, Symbol, evaluate, parse_str};
+
+        evaluate_test!(own_value, ["x = 1", "Clear[x]"], "x", "x");
+        evaluate_test!(
+            down_values,
+            ["f[1] := 2", "f[x_] := x", "Clear[f]"],
+            "f[1]",
+            "f[1]"
+        );
+        evaluate_test!(returns_null, ["x = 1"], "Clear[x]", "Null");
+        evaluate_test!(undefined_symbol, "Clear[x]", "Null");
+        evaluate_test!(read_only, "Clear[Plus]", "Clear[Plus]");
+
+        #[test]
+        fn keeps_attributes() {
+            let mut context = Context::new_global_context();
+            context
+                .set_attributes(&Symbol::new("f"), Attribute::HoldAll.into())
+                .unwrap();
+
+            evaluate(parse_str("f[x_] := x").unwrap(), &mut context).unwrap();
+            evaluate(parse_str("Clear[f]").unwrap(), &mut context).unwrap();
+
+            assert_eq!(
+                context.get_attributes(&Symbol::new("f")),
+                Attribute::HoldAll.into()
+            );
+            assert_eq!(
+                evaluate(parse_str("f[1]").unwrap(), &mut context).unwrap(),
+                parse_str("f[1]").unwrap()
+            );
+        }
+    }
+
+    mod unset {
+        evaluate_test!(own_value, ["x = 1", "x =."], "x", "x");
+        evaluate_test!(explicit, ["x = 1", "Unset[x]"], "x", "x");
+        evaluate_test!(
+            single_down_value,
+            ["f[1] := 2", "f[2] := 3", "f[1] =."],
+            "{f[1], f[2]}",
+            "{f[1], 3}"
+        );
+        evaluate_test!(returns_null, ["x = 1"], "x =.", "Null");
+        evaluate_test!(real_literal, ["x =.5"], "x", "0.5");
+    }
+
+    mod remove {
+        evaluate_test!(own_value, ["x = 1", "Remove[x]"], "x", "x");
+        evaluate_test!(down_values, ["f[x_] := x", "Remove[f]"], "f[1]", "f[1]");
+        evaluate_test!(read_only, "Remove[Plus]", "Remove[Plus]");
+    }
+
+    mod compound_expression {
+        evaluate_test!(returns_last, "(a = 1; a + 1)", "2");
+        evaluate_test!(several_assignments, "a = 1; b = 2; a + b", "3");
+        evaluate_test!(trailing_semicolon, "a = 1;", "Null");
+        evaluate_test!(side_effects_persist, ["a = 1;"], "a", "1");
+        evaluate_test!(empty, "CompoundExpression[]", "Null");
+    }
+
+    mod function {
+        evaluate_test!(single_slot, "(# + 1 &)[5]", "6");
+        evaluate_test!(numbered_slots, "(#1 + #2 &)[2, 3]", "5");
+        evaluate_test!(repeated_slot, "(# * # &)[3]", "9");
+        evaluate_test!(mapped, "(# + 1 &) /@ {1, 2, 3}", "{2, 3, 4}");
+        evaluate_test!(assigned, ["f = #^2 &"], "f[3]", "9");
+        evaluate_test!(nested, "(# + (# * 2 &)[3] &)[1]", "7");
+        evaluate_test!(held_body, "(1 + 1 &)", "Function[1 + 1]");
+        evaluate_test!(missing_argument, "(#2 &)[1]", "(#2 &)[1]");
+        evaluate_test!(named_parameter, "Function[{x}, x^2][4]", "16");
+        evaluate_test!(named_parameters, "Function[{x, y}, x - y][5, 3]", "2");
+        evaluate_test!(bare_parameter, "Function[x, x + 1][2]", "3");
+        evaluate_test!(extra_arguments, "Function[{x}, x][1, 2]", "1");
+        evaluate_test!(
+            missing_parameter,
+            "Function[{x, y}, x][1]",
+            "Function[{x, y}, x][1]"
+        );
+        evaluate_test!(
+            named_parameters_ignore_slots,
+            "Function[{x}, {x, #}][1]",
+            "{1, #}"
+        );
+    }
+
+    mod r#if {
+        evaluate_test!(true_branch, "If[True, a, b]", "a");
+        evaluate_test!(false_branch, "If[False, a, b]", "b");
+        evaluate_test!(undecided, "If[x, a, b]", "If[x, a, b]");
+        evaluate_test!(true_without_else, "If[True, a]", "a");
+        evaluate_test!(false_without_else, "If[False, a]", "Null");
+        evaluate_test!(holds_branches, "If[x, 1 + 2, 3 + 4]", "If[x, 1 + 2, 3 + 4]");
+    }
+
+    mod same_q {
+        evaluate_test!(same_integers, "1 === 1", "True");
+        evaluate_test!(integer_and_real, "1 === 1.0", "False");
+        evaluate_test!(same_normals, "f[a] === f[a]", "True");
+        evaluate_test!(different_normals, "f[a] === f[b]", "False");
+        evaluate_test!(variadic_same, "SameQ[a, a, a]", "True");
+        evaluate_test!(variadic_different, "SameQ[a, a, b]", "False");
+        evaluate_test!(no_arguments, "SameQ[]", "True");
+    }
+
+    mod unsame_q {
+        evaluate_test!(same_integers, "1 =!= 1", "False");
+        evaluate_test!(integer_and_real, "1 =!= 1.0", "True");
+        evaluate_test!(variadic_distinct, "UnsameQ[a, b, c]", "True");
+        evaluate_test!(variadic_repeated, "UnsameQ[a, b, a]", "False");
+    }
+
+    mod length {
+        evaluate_test!(list, "Length[{a, b, c}]", "3");
+        evaluate_test!(normal, "Length[f[a, b]]", "2");
+        evaluate_test!(empty, "Length[f[]]", "0");
+        evaluate_test!(atom, "Length[5]", "0");
+    }
+}
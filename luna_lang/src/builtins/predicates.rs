@@ -0,0 +1,368 @@
+use super::{bool_symbol, try_complex};
+use crate::{Attribute, Context, EvalResult, Expr, Symbol, SymbolValue, ValueType};
+use crate::{parse, sym};
+use std::cmp::Ordering;
+
+/// Registers the `IntegerQ` builtin symbol.
+///
+/// - `Attributes[IntegerQ] = { ReadOnly, AttributesReadOnly }`
+/// - `IntegerQ[expr_] := built-in`
+pub(crate) fn register_integer_q_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(IntegerQ),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("IntegerQ[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    EvalResult::Changed(Expr::from(bool_symbol(expr.try_integer().is_some())))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(IntegerQ),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `NumberQ` builtin symbol.
+///
+/// - `Attributes[NumberQ] = { ReadOnly, AttributesReadOnly }`
+/// - `NumberQ[expr_] := built-in`
+pub(crate) fn register_number_q_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(NumberQ),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("NumberQ[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    EvalResult::Changed(Expr::from(bool_symbol(is_number(expr))))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(NumberQ),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `StringQ` builtin symbol.
+///
+/// - `Attributes[StringQ] = { ReadOnly, AttributesReadOnly }`
+/// - `StringQ[expr_] := built-in`
+pub(crate) fn register_string_q_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(StringQ),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("StringQ[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    EvalResult::Changed(Expr::from(bool_symbol(expr.try_string().is_some())))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(StringQ),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `SymbolQ` builtin symbol.
+///
+/// - `Attributes[SymbolQ] = { ReadOnly, AttributesReadOnly }`
+/// - `SymbolQ[expr_] := built-in`
+pub(crate) fn register_symbol_q_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(SymbolQ),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("SymbolQ[expr_]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let expr = &arguments[&Symbol::new("expr")];
+
+                    EvalResult::Changed(Expr::from(bool_symbol(expr.try_symbol().is_some())))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(SymbolQ),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `EvenQ` builtin symbol, which tests whether a number is an even integer. Numbers
+/// which are not integers are never even, while anything else is left unevaluated.
+///
+/// - `Attributes[EvenQ] = { ReadOnly, AttributesReadOnly }`
+/// - `EvenQ[expr_] := built-in`
+pub(crate) fn register_even_q_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(EvenQ),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("EvenQ[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let number = &arguments[&Symbol::new("expr")];
+
+                    match number.try_integer() {
+                        Some(integer) => {
+                            EvalResult::Changed(Expr::from(bool_symbol(integer.is_even())))
+                        }
+                        None if is_number(number) => EvalResult::Changed(Expr::from(sym!(False))),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(EvenQ),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `OddQ` builtin symbol, which tests whether a number is an odd integer. Numbers
+/// which are not integers are never odd, while anything else is left unevaluated.
+///
+/// - `Attributes[OddQ] = { ReadOnly, AttributesReadOnly }`
+/// - `OddQ[expr_] := built-in`
+pub(crate) fn register_odd_q_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(OddQ),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("OddQ[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let number = &arguments[&Symbol::new("expr")];
+
+                    match number.try_integer() {
+                        Some(integer) => {
+                            EvalResult::Changed(Expr::from(bool_symbol(integer.is_odd())))
+                        }
+                        None if is_number(number) => EvalResult::Changed(Expr::from(sym!(False))),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(OddQ),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Positive` builtin symbol, which tests whether a real number is greater than
+/// zero. Anything other than an integer, rational or real is left unevaluated.
+///
+/// - `Attributes[Positive] = { ReadOnly, AttributesReadOnly }`
+/// - `Positive[expr_] := built-in`
+pub(crate) fn register_positive_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Positive),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Positive[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| match sign(&arguments[&Symbol::new("expr")]) {
+                    Some(sign) => {
+                        EvalResult::Changed(Expr::from(bool_symbol(sign == Ordering::Greater)))
+                    }
+                    None => EvalResult::Unchanged(expr),
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Positive),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `Negative` builtin symbol, which tests whether a real number is less than zero.
+/// Anything other than an integer, rational or real is left unevaluated.
+///
+/// - `Attributes[Negative] = { ReadOnly, AttributesReadOnly }`
+/// - `Negative[expr_] := built-in`
+pub(crate) fn register_negative_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Negative),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("Negative[expr_]"),
+                condition: None,
+                built_in: |arguments, expr, _| match sign(&arguments[&Symbol::new("expr")]) {
+                    Some(sign) => {
+                        EvalResult::Changed(Expr::from(bool_symbol(sign == Ordering::Less)))
+                    }
+                    None => EvalResult::Unchanged(expr),
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Negative),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Whether an expression is a number of any kind, as for `NumberQ`.
+fn is_number(expr: &Expr) -> bool {
+    expr.try_integer().is_some()
+        || expr.try_rational().is_some()
+        || expr.try_real().is_some()
+        || try_complex(expr).is_some()
+}
+
+/// Compares an integer, rational or real with zero. Returns `None` for anything else, including a
+/// real which is not a number.
+fn sign(expr: &Expr) -> Option<Ordering> {
+    if let Some(integer) = expr.try_integer() {
+        return Some(integer.cmp0());
+    }
+
+    if let Some(rational) = expr.try_rational() {
+        return Some(rational.cmp0());
+    }
+
+    expr.try_real()?.as_float().cmp0()
+}
+
+#[cfg(test)]
+mod tests {
+    mod integer_q {
+        evaluate_test!(integer, "IntegerQ[1]", "True");
+        evaluate_test!(negative_integer, "IntegerQ[-3]", "True");
+        evaluate_test!(real, "IntegerQ[1.5]", "False");
+        evaluate_test!(string, "IntegerQ[\"1\"]", "False");
+        evaluate_test!(symbol, "IntegerQ[x]", "False");
+        evaluate_test!(normal, "IntegerQ[f[1]]", "False");
+        evaluate_test!(evaluated_argument, "IntegerQ[1 + 2]", "True");
+    }
+
+    mod number_q {
+        evaluate_test!(integer, "NumberQ[1]", "True");
+        evaluate_test!(real, "NumberQ[1.5]", "True");
+        evaluate_test!(string, "NumberQ[\"1\"]", "False");
+        evaluate_test!(symbol, "NumberQ[x]", "False");
+        evaluate_test!(normal, "NumberQ[f[1]]", "False");
+    }
+
+    mod string_q {
+        evaluate_test!(string, "StringQ[\"abc\"]", "True");
+        evaluate_test!(integer, "StringQ[1]", "False");
+        evaluate_test!(symbol, "StringQ[abc]", "False");
+        evaluate_test!(normal, "StringQ[f[\"abc\"]]", "False");
+    }
+
+    mod symbol_q {
+        evaluate_test!(symbol, "SymbolQ[x]", "True");
+        evaluate_test!(integer, "SymbolQ[1]", "False");
+        evaluate_test!(string, "SymbolQ[\"x\"]", "False");
+        evaluate_test!(normal, "SymbolQ[f[x]]", "False");
+        evaluate_test!(evaluated_argument, ["x = 1"], "SymbolQ[x]", "False");
+    }
+
+    mod conditions {
+        evaluate_test!(
+            integer_argument,
+            ["f[x_] := x + 1 /; IntegerQ[x]"],
+            "{f[1], f[1.5]}",
+            "{2, f[1.5]}"
+        );
+        evaluate_test!(
+            pattern_test,
+            ["f[x_?NumberQ] := x + 1"],
+            "{f[1], f[1.5], f[a]}",
+            "{2, 2.5, f[a]}"
+        );
+    }
+
+    mod even_q {
+        evaluate_test!(even, "EvenQ[4]", "True");
+        evaluate_test!(odd, "EvenQ[3]", "False");
+        evaluate_test!(zero, "EvenQ[0]", "True");
+        evaluate_test!(negative, "EvenQ[-2]", "True");
+        evaluate_test!(real, "EvenQ[2.0]", "False");
+        evaluate_test!(symbol, "EvenQ[x]", "EvenQ[x]");
+        evaluate_test!(with_select, "Select[{1, 2, 3, 4}, EvenQ]", "{2, 4}");
+    }
+
+    mod odd_q {
+        evaluate_test!(odd, "OddQ[3]", "True");
+        evaluate_test!(even, "OddQ[4]", "False");
+        evaluate_test!(negative, "OddQ[-1]", "True");
+        evaluate_test!(rational, "OddQ[Rational[1, 3]]", "False");
+        evaluate_test!(symbol, "OddQ[x]", "OddQ[x]");
+    }
+
+    mod positive {
+        evaluate_test!(integer, "Positive[2]", "True");
+        evaluate_test!(negative_integer, "Positive[-1]", "False");
+        evaluate_test!(zero, "Positive[0]", "False");
+        evaluate_test!(real, "Positive[0.5]", "True");
+        evaluate_test!(rational, "Positive[Rational[-1, 2]]", "False");
+        evaluate_test!(symbol, "Positive[x]", "Positive[x]");
+        evaluate_test!(
+            pattern_test,
+            ["f[x_?Positive] := x"],
+            "{f[1], f[-1]}",
+            "{1, f[-1]}"
+        );
+    }
+
+    mod negative {
+        evaluate_test!(integer, "Negative[-2]", "True");
+        evaluate_test!(positive_real, "Negative[2.5]", "False");
+        evaluate_test!(zero, "Negative[0.0]", "False");
+        evaluate_test!(negative_real, "Negative[-0.5]", "True");
+        evaluate_test!(symbol, "Negative[x]", "Negative[x]");
+    }
+}
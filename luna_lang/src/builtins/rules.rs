@@ -0,0 +1,151 @@
+use crate::{Attribute, Context, EvalResult, Expr, Symbol, SymbolValue, ValueType};
+use crate::{evaluate, parse, sym};
+
+/// Registers the `Rule` builtin symbol.
+///
+/// - `Attributes[Rule] = { ReadOnly, AttributesReadOnly }`
+pub(crate) fn register_rule_builtin(context: &mut Context) {
+    context
+        .set_attributes(
+            &sym!(Rule),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `RuleDelayed` builtin symbol.
+///
+/// - `Attributes[RuleDelayed] = { ReadOnly, AttributesReadOnly, HoldRest }`
+pub(crate) fn register_rule_delayed_builtin(context: &mut Context) {
+    context
+        .set_attributes(
+            &sym!(RuleDelayed),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldRest,
+        )
+        .unwrap();
+}
+
+/// Registers the `ReplaceAll` builtin symbol.
+///
+/// - `Attributes[ReplaceAll] = { ReadOnly, AttributesReadOnly }`
+/// - `ReplaceAll[expr_, rules_] := built-in`
+pub(crate) fn register_replace_all_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(ReplaceAll),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("ReplaceAll[expr_, rules_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let rules = match parse_rules(&arguments[&Symbol::new("rules")]) {
+                        Some(rules) => rules,
+                        None => return Ok(EvalResult::Unchanged(expr)),
+                    };
+
+                    Ok(EvalResult::Changed(
+                        arguments[&Symbol::new("expr")].replace_all(&rules, context),
+                    ))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(ReplaceAll),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `ReplaceRepeated` builtin symbol.
+///
+/// - `Attributes[ReplaceRepeated] = { ReadOnly, AttributesReadOnly }`
+/// - `ReplaceRepeated[expr_, rules_] := built-in`
+pub(crate) fn register_replace_repeated_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(ReplaceRepeated),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("ReplaceRepeated[expr_, rules_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let rules = match parse_rules(&arguments[&Symbol::new("rules")]) {
+                        Some(rules) => rules,
+                        None => return Ok(EvalResult::Unchanged(expr)),
+                    };
+
+                    let mut current = arguments[&Symbol::new("expr")].clone();
+
+                    // Each pass is evaluated before the next, so that rules such as `x -> x + 1`
+                    // do not build an ever deeper expression. Rules which never reach a fixed
+                    // point give up after the iteration limit with the latest result.
+                    for _ in 0..context.iteration_limit() {
+                        let next = evaluate(current.replace_all(&rules, context), context)?;
+
+                        if next == current {
+                            break;
+                        }
+
+                        current = next;
+                    }
+
+                    Ok(EvalResult::Changed(current))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(ReplaceRepeated),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Parses either a single `Rule[lhs, rhs]` or `RuleDelayed[lhs, rhs]`, or a list of them, into
+/// pairs of left and right hand sides. Returns `None` if anything other than a rule is found.
+pub(crate) fn parse_rules(rules: &Expr) -> Option<Vec<(Expr, Expr)>> {
+    match rules.try_normal_head(&sym!(List)) {
+        Some(list) => list.elements().iter().map(parse_rule).collect(),
+        None => Some(vec![parse_rule(rules)?]),
+    }
+}
+
+/// Parses a single `Rule[lhs, rhs]` or `RuleDelayed[lhs, rhs]` into its left and right hand sides.
+pub(crate) fn parse_rule(rule: &Expr) -> Option<(Expr, Expr)> {
+    let normal = rule
+        .try_normal_head(&sym!(Rule))
+        .or_else(|| rule.try_normal_head(&sym!(RuleDelayed)))?;
+
+    match normal.elements() {
+        [lhs, rhs] => Some((lhs.clone(), rhs.clone())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod replace_all {
+        evaluate_test!(single_rule, "{a, b, c} /. a -> x", "{x, b, c}");
+        evaluate_test!(pattern_rule, "f[x] /. f[y_] -> y", "x");
+        evaluate_test!(list_of_rules, "{a, b} /. {a -> b, b -> a}", "{b, a}");
+        evaluate_test!(outermost_first, "f[f[a]] /. f[x_] -> x", "f[a]");
+        evaluate_test!(head, "f[a] /. f -> g", "g[a]");
+        evaluate_test!(delayed_rule, "{1, 2} /. x_Integer :> x + 1", "{2, 3}");
+        evaluate_test!(no_match, "{a, b} /. c -> d", "{a, b}");
+        evaluate_test!(not_a_rule, "{a, b} /. c", "ReplaceAll[{a, b}, c]");
+    }
+
+    mod replace_repeated {
+        evaluate_test!(fixpoint, "{a, f[a]} //. a -> b", "{b, f[b]}");
+        evaluate_test!(chained_rules, "a //. {a -> b, b -> c}", "c");
+        evaluate_test!(nested_rewrites, "f[f[f[x]]] //. f[y_] -> y", "x");
+        evaluate_test!(cycle_is_capped, "a //. {a -> b, b -> a}", "a");
+        evaluate_test!(never_terminates, "x //. x -> x + 1", "4096 + x");
+        evaluate_test!(not_a_rule, "a //. b", "ReplaceRepeated[a, b]");
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{
+    Attribute, Context, EvalError, EvalResult, Expr, SolutionSet, Symbol, SymbolValue, ValueType,
+};
+use crate::{evaluate, parse, replace_all, sym};
+
+/// Registers the `Block` builtin symbol, which evaluates its body with the values of the given
+/// symbols temporarily cleared, or set to the given initial values. The previous values of the
+/// symbols are restored afterwards.
+///
+/// - `Attributes[Block] = { ReadOnly, AttributesReadOnly, HoldAll }`
+/// - `Block[vars_, body_] := built-in`
+pub(crate) fn register_block_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Block),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Block[vars_, body_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let Some(variables) = local_variables(&arguments[&Symbol::new("vars")]) else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    let symbols = variables
+                        .iter()
+                        .map(|(symbol, _)| symbol.clone())
+                        .collect::<Vec<_>>();
+
+                    if symbols
+                        .iter()
+                        .any(|symbol| context.get_attributes(symbol).read_only())
+                    {
+                        return Ok(EvalResult::Unchanged(expr));
+                    }
+
+                    // The initial values are evaluated before any of the symbols are cleared.
+                    let values = variables
+                        .into_iter()
+                        .map(|(symbol, value)| {
+                            Ok((symbol, evaluate_initial_value(value, context)?))
+                        })
+                        .collect::<Result<Vec<_>, EvalError>>()?;
+
+                    let snapshot = context.snapshot_symbols(&symbols);
+
+                    for (symbol, value) in values {
+                        context.clear_values(&symbol).unwrap();
+
+                        if let Some(value) = value {
+                            set_own_value(&symbol, value, context);
+                        }
+                    }
+
+                    // The previous values are restored even if the body fails to evaluate.
+                    let result = evaluate(arguments[&Symbol::new("body")].clone(), context);
+                    context.restore(snapshot);
+
+                    Ok(EvalResult::Changed(result?))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Block),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
+/// Registers the `Module` builtin symbol, which replaces each of the given symbols within its body
+/// with a new symbol unique to this evaluation of the module, for example `x` with `x$1`.
+///
+/// - `Attributes[Module] = { ReadOnly, AttributesReadOnly, HoldAll }`
+/// - `Module[vars_, body_] := built-in`
+pub(crate) fn register_module_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(Module),
+            ValueType::DownValue,
+            SymbolValue::BuiltInMut {
+                pattern: parse!("Module[vars_, body_]"),
+                condition: None,
+                built_in: |arguments, expr, context| {
+                    let Some(variables) = local_variables(&arguments[&Symbol::new("vars")]) else {
+                        return Ok(EvalResult::Unchanged(expr));
+                    };
+
+                    let module_number = context.next_module_number();
+                    let mut bindings = SolutionSet::new();
+
+                    for (symbol, value) in variables {
+                        let local = Symbol::new(&format!("{}${}", symbol, module_number));
+
+                        if let Some(value) = evaluate_initial_value(value, context)? {
+                            set_own_value(&local, value, context);
+                        }
+
+                        bindings.insert(symbol, Expr::from(local));
+                    }
+
+                    Ok(EvalResult::Changed(
+                        replace_all(&bindings, arguments[&Symbol::new("body")].clone()).into_expr(),
+                    ))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(Module),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::HoldAll,
+        )
+        .unwrap();
+}
+
+/// Parses a list of local variables such as `{x, y = 1}` into each symbol and its unevaluated
+/// initial value, if any. Returns `None` if the list is malformed.
+fn local_variables(variables: &Expr) -> Option<Vec<(Symbol, Option<Expr>)>> {
+    variables
+        .try_normal_head(&sym!(List))?
+        .elements()
+        .iter()
+        .map(|variable| {
+            if let Some(symbol) = variable.try_symbol() {
+                return Some((symbol.clone(), None));
+            }
+
+            match variable.try_normal_head(&sym!(Set))?.elements() {
+                [symbol, value] => Some((symbol.try_symbol()?.clone(), Some(value.clone()))),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn evaluate_initial_value(
+    value: Option<Expr>,
+    context: &mut Context,
+) -> Result<Option<Expr>, EvalError> {
+    value.map(|value| evaluate(value, context)).transpose()
+}
+
+fn set_own_value(symbol: &Symbol, value: Expr, context: &mut Context) {
+    context
+        .set_value(
+            symbol,
+            ValueType::OwnValue,
+            SymbolValue::Definitions {
+                pattern: Expr::from(symbol.clone()),
+                condition: None,
+                ground: value,
+            },
+        )
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    mod block {
+        use crate::{Context, evaluate, parse_str};
+
+        evaluate_test!(initial_value, ["x = 1"], "{Block[{x = 2}, x], x}", "{2, 1}");
+        evaluate_test!(cleared, ["x = 1"], "Block[{x}, SameQ[x, 1]]", "False");
+        evaluate_test!(result_evaluated_afterwards, ["x = 1"], "Block[{x}, x]", "1");
+        evaluate_test!(
+            assignment_does_not_leak,
+            "{Block[{x}, x = 5; x], x}",
+            "{5, x}"
+        );
+        evaluate_test!(
+            dynamic_scope,
+            ["f[y_] := x + y"],
+            "{Block[{x = 1}, f[2]], f[2]}",
+            "{3, 2 + x}"
+        );
+        evaluate_test!(
+            initial_values_evaluated_outside,
+            ["x = 1"],
+            "Block[{x = x + 1}, x]",
+            "2"
+        );
+        evaluate_test!(malformed, "Block[x, x]", "Block[x, x]");
+        evaluate_test!(read_only, "Block[{Plus}, 1 + 2]", "Block[{Plus}, 1 + 2]");
+
+        #[test]
+        fn restored_after_error() {
+            let mut context = Context::new_global_context();
+
+            evaluate(parse_str("x = 1").unwrap(), &mut context).unwrap();
+
+            assert!(evaluate(parse_str("Block[{x = 2}, 5 = x]").unwrap(), &mut context).is_err());
+            assert_eq!(
+                evaluate(parse_str("x").unwrap(), &mut context),
+                Ok(parse_str("1").unwrap())
+            );
+        }
+    }
+
+    mod module {
+        evaluate_test!(initial_value, "Module[{x = 1}, x + 1]", "2");
+        evaluate_test!(renamed, "Module[{x, y}, {x, y}]", "{x$1, y$1}");
+        evaluate_test!(
+            unique_names,
+            "{Module[{x}, x], Module[{x}, x]}",
+            "{x$1, x$2}"
+        );
+        evaluate_test!(
+            global_untouched,
+            ["x = 5"],
+            "{Module[{x = 1}, x], x}",
+            "{1, 5}"
+        );
+        evaluate_test!(
+            assignment_does_not_leak,
+            "{Module[{x}, x = 2; x], x}",
+            "{2, x}"
+        );
+        evaluate_test!(
+            lexical_scope,
+            ["f[y_] := x + y"],
+            "Module[{x = 1}, f[2]]",
+            "2 + x"
+        );
+        evaluate_test!(malformed, "Module[{1}, x]", "Module[{1}, x]");
+    }
+}
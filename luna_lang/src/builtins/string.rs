@@ -0,0 +1,288 @@
+use crate::{
+    Attribute, BigInteger, Context, EvalResult, Expr, Normal, Symbol, SymbolValue, ValueType,
+};
+use crate::{parse, sym, try_sequence};
+use std::ops::Range;
+
+/// Registers the `StringJoin` builtin symbol, which concatenates adjacent strings, for example
+/// `"a" <> "b" <> x` is `"ab" <> x`.
+///
+/// - `Attributes[StringJoin] = { ReadOnly, AttributesReadOnly, Associative }`
+/// - `StringJoin[exprs___] := built-in`
+pub(crate) fn register_string_join_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(StringJoin),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("StringJoin[exprs___]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let exprs = &arguments[&Symbol::new("exprs")];
+                    let expr_elements =
+                        try_sequence(exprs).expect("expected exprs___ to match Sequence[]");
+
+                    let mut new_elements: Vec<Expr> = Vec::with_capacity(expr_elements.len());
+
+                    for element in expr_elements {
+                        match (new_elements.last_mut(), element.try_string()) {
+                            (Some(previous), Some(string)) if previous.try_string().is_some() => {
+                                let joined = previous.try_string().unwrap().clone() + string;
+                                *previous = Expr::from(joined);
+                            }
+                            _ => new_elements.push(element.clone()),
+                        }
+                    }
+
+                    match new_elements.as_slice() {
+                        [] => EvalResult::Changed(Expr::from("")),
+                        [joined] if joined.try_string().is_some() => {
+                            EvalResult::Changed(joined.clone())
+                        }
+                        _ if new_elements != expr_elements => EvalResult::Changed(Expr::from(
+                            Normal::new(sym!(StringJoin), new_elements),
+                        )),
+                        _ => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(StringJoin),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly + Attribute::Associative,
+        )
+        .unwrap();
+}
+
+/// Registers the `StringLength` builtin symbol, which counts the characters of a string.
+///
+/// - `Attributes[StringLength] = { ReadOnly, AttributesReadOnly }`
+/// - `StringLength[s_String] := built-in`
+pub(crate) fn register_string_length_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(StringLength),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("StringLength[s_String]"),
+                condition: None,
+                built_in: |arguments, _, _| {
+                    let string = arguments[&Symbol::new("s")].try_string().unwrap();
+
+                    EvalResult::Changed(Expr::from(BigInteger::from(string.chars().count())))
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(StringLength),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `StringTake` builtin symbol, which takes the characters of a string given by a
+/// span, see [`string_span`].
+///
+/// - `Attributes[StringTake] = { ReadOnly, AttributesReadOnly }`
+/// - `StringTake[s_String, spec_] := built-in`
+pub(crate) fn register_string_take_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(StringTake),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("StringTake[s_String, spec_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let chars = arguments[&Symbol::new("s")]
+                        .try_string()
+                        .unwrap()
+                        .chars()
+                        .collect::<Vec<_>>();
+
+                    match string_span(chars.len(), &arguments[&Symbol::new("spec")]) {
+                        Some(span) => {
+                            EvalResult::Changed(Expr::from(chars[span].iter().collect::<String>()))
+                        }
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(StringTake),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Registers the `StringDrop` builtin symbol, which removes the characters of a string given by a
+/// span, see [`string_span`].
+///
+/// - `Attributes[StringDrop] = { ReadOnly, AttributesReadOnly }`
+/// - `StringDrop[s_String, spec_] := built-in`
+pub(crate) fn register_string_drop_builtin(context: &mut Context) {
+    context
+        .set_value(
+            &sym!(StringDrop),
+            ValueType::DownValue,
+            SymbolValue::BuiltIn {
+                pattern: parse!("StringDrop[s_String, spec_]"),
+                condition: None,
+                built_in: |arguments, expr, _| {
+                    let chars = arguments[&Symbol::new("s")]
+                        .try_string()
+                        .unwrap()
+                        .chars()
+                        .collect::<Vec<_>>();
+
+                    match string_span(chars.len(), &arguments[&Symbol::new("spec")]) {
+                        Some(span) => EvalResult::Changed(Expr::from(
+                            chars[..span.start]
+                                .iter()
+                                .chain(&chars[span.end..])
+                                .collect::<String>(),
+                        )),
+                        None => EvalResult::Unchanged(expr),
+                    }
+                },
+            },
+        )
+        .unwrap();
+
+    context
+        .set_attributes(
+            &sym!(StringDrop),
+            Attribute::ReadOnly + Attribute::AttributesReadOnly,
+        )
+        .unwrap();
+}
+
+/// Resolves a span of the characters of a string of length `len`, returning their positions.
+/// Positions are 1-based, and negative positions count backwards from the last character, as for
+/// `Part`. Returns `None` if the span is malformed or out of range.
+///
+/// - `n` spans the first `n` characters, or the last `-n` if negative.
+/// - `{m}` spans the `m`th character.
+/// - `{m, n}` spans the `m`th through `n`th characters.
+fn string_span(len: usize, spec: &Expr) -> Option<Range<usize>> {
+    if let Some(n) = spec.try_integer() {
+        let n = n.to_isize()?;
+        let count = n.unsigned_abs();
+
+        if count > len {
+            return None;
+        }
+
+        return Some(if n >= 0 { 0..count } else { len - count..len });
+    }
+
+    let (first, last) = match spec.try_normal_head(&sym!(List))?.elements() {
+        [m] => (m, m),
+        [m, n] => (m, n),
+        _ => return None,
+    };
+
+    let start = string_position(len, first)?;
+    let end = string_position(len, last)? + 1;
+
+    (start <= end).then_some(start..end)
+}
+
+/// Resolves a single 1-based, possibly negative, position within a string of length `len` to a
+/// 0-based offset.
+fn string_position(len: usize, position: &Expr) -> Option<usize> {
+    let position = position.try_integer()?.to_isize()?;
+
+    let offset = if position > 0 {
+        position - 1
+    } else {
+        len as isize + position
+    };
+
+    usize::try_from(offset).ok().filter(|offset| *offset < len)
+}
+
+#[cfg(test)]
+mod tests {
+    mod string_join {
+        evaluate_test!(two_strings, "\"foo\" <> \"bar\"", "\"foobar\"");
+        evaluate_test!(
+            several_strings,
+            "StringJoin[\"a\", \"b\", \"c\"]",
+            "\"abc\""
+        );
+        evaluate_test!(empty, "StringJoin[]", "\"\"");
+        evaluate_test!(single_string, "StringJoin[\"a\"]", "\"a\"");
+        evaluate_test!(
+            symbolic,
+            "\"a\" <> x <> \"b\"",
+            "StringJoin[\"a\", x, \"b\"]"
+        );
+        evaluate_test!(
+            partially_joined,
+            "\"a\" <> \"b\" <> x <> \"c\" <> \"d\"",
+            "StringJoin[\"ab\", x, \"cd\"]"
+        );
+        evaluate_test!(
+            evaluated_later,
+            ["y = \"c\""],
+            "\"a\" <> \"b\" <> y",
+            "\"abc\""
+        );
+        evaluate_test!(unicode, "\"ü\" <> \"日本\"", "\"ü日本\"");
+    }
+
+    mod string_length {
+        evaluate_test!(ascii, "StringLength[\"hello\"]", "5");
+        evaluate_test!(empty, "StringLength[\"\"]", "0");
+        evaluate_test!(multi_byte, "StringLength[\"日本語\"]", "3");
+        evaluate_test!(not_a_string, "StringLength[x]", "StringLength[x]");
+    }
+
+    mod string_take {
+        evaluate_test!(first, "StringTake[\"abcdef\", 2]", "\"ab\"");
+        evaluate_test!(last, "StringTake[\"abcdef\", -2]", "\"ef\"");
+        evaluate_test!(none, "StringTake[\"abcdef\", 0]", "\"\"");
+        evaluate_test!(single, "StringTake[\"abcdef\", {3}]", "\"c\"");
+        evaluate_test!(range, "StringTake[\"abcdef\", {2, 4}]", "\"bcd\"");
+        evaluate_test!(
+            negative_range,
+            "StringTake[\"abcdef\", {-3, -1}]",
+            "\"def\""
+        );
+        evaluate_test!(multi_byte, "StringTake[\"añb日c\", {2, 4}]", "\"ñb日\"");
+        evaluate_test!(
+            out_of_range,
+            "StringTake[\"abc\", 4]",
+            "StringTake[\"abc\", 4]"
+        );
+        evaluate_test!(
+            zero_position,
+            "StringTake[\"abc\", {0}]",
+            "StringTake[\"abc\", {0}]"
+        );
+    }
+
+    mod string_drop {
+        evaluate_test!(first, "StringDrop[\"abcdef\", 2]", "\"cdef\"");
+        evaluate_test!(last, "StringDrop[\"abcdef\", -2]", "\"abcd\"");
+        evaluate_test!(single, "StringDrop[\"abcdef\", {3}]", "\"abdef\"");
+        evaluate_test!(range, "StringDrop[\"abcdef\", {2, 4}]", "\"aef\"");
+        evaluate_test!(multi_byte, "StringDrop[\"日本語\", 1]", "\"本語\"");
+        evaluate_test!(
+            out_of_range,
+            "StringDrop[\"abc\", {5}]",
+            "StringDrop[\"abc\", {5}]"
+        );
+    }
+}
@@ -1,47 +1,137 @@
 use crate::Symbol;
-use std::cell::LazyCell;
+use std::sync::LazyLock;
 
-pub struct BuiltinSymbols {
-    pub Sequence: Symbol,
-    pub Plus: Symbol,
-    pub Blank: Symbol,
-    pub BlankSequence: Symbol,
-    pub BlankNullSequence: Symbol,
-    pub Pattern: Symbol,
-    pub Condition: Symbol,
-    pub Set: Symbol,
-    pub SetDelayed: Symbol,
-    pub Head: Symbol,
-    pub Hold: Symbol,
-    pub Null: Symbol,
-    pub String: Symbol,
-    pub Integer: Symbol,
-    pub Real: Symbol,
-    pub Symbol: Symbol,
-    pub Subtract: Symbol,
-    pub Times: Symbol,
+/// Declares the builtin symbols, each a field of `BuiltinSymbols` named after the symbol.
+macro_rules! builtin_symbols {
+    ($($name:ident),* $(,)?) => {
+        #[allow(non_snake_case)]
+        pub struct BuiltinSymbols {
+            $(pub $name: Symbol,)*
+        }
+
+        /// The builtin symbols, created once on first use. This is a `static` rather than a
+        /// `const`, as a `const` would be re-initialized, creating every symbol again, at each use.
+        pub static BUILTIN_SYMBOLS: LazyLock<BuiltinSymbols> = LazyLock::new(|| BuiltinSymbols {
+            $($name: Symbol::new(stringify!($name)),)*
+        });
+
+        impl BuiltinSymbols {
+            /// Returns every builtin symbol.
+            pub fn all(&self) -> Vec<&Symbol> {
+                vec![$(&self.$name),*]
+            }
+        }
+    };
 }
 
-pub const BUILTIN_SYMBOLS: LazyCell<BuiltinSymbols> = LazyCell::new(|| BuiltinSymbols {
-    Sequence: Symbol::new("Sequence"),
-    Plus: Symbol::new("Plus"),
-    Blank: Symbol::new("Blank"),
-    BlankSequence: Symbol::new("BlankSequence"),
-    BlankNullSequence: Symbol::new("BlankNullSequence"),
-    Pattern: Symbol::new("Pattern"),
-    Condition: Symbol::new("Condition"),
-    Set: Symbol::new("Set"),
-    SetDelayed: Symbol::new("SetDelayed"),
-    Head: Symbol::new("Head"),
-    Hold: Symbol::new("Hold"),
-    Null: Symbol::new("Null"),
-    String: Symbol::new("String"),
-    Integer: Symbol::new("Integer"),
-    Real: Symbol::new("Real"),
-    Symbol: Symbol::new("Symbol"),
-    Subtract: Symbol::new("Subtract"),
-    Times: Symbol::new("Times"),
-});
+builtin_symbols! {
+    Sequence,
+    Plus,
+    Blank,
+    BlankSequence,
+    BlankNullSequence,
+    Pattern,
+    Condition,
+    Set,
+    SetDelayed,
+    Head,
+    Hold,
+    Null,
+    String,
+    Integer,
+    Real,
+    Symbol,
+    Subtract,
+    Times,
+    Divide,
+    Power,
+    Factorial,
+    Map,
+    Apply,
+    If,
+    True,
+    False,
+    SameQ,
+    UnsameQ,
+    Less,
+    Greater,
+    LessEqual,
+    GreaterEqual,
+    Equal,
+    Unequal,
+    Length,
+    First,
+    Last,
+    Rest,
+    Most,
+    Append,
+    Prepend,
+    Part,
+    Sort,
+    Range,
+    List,
+    Abs,
+    Min,
+    Max,
+    Mod,
+    Quotient,
+    GCD,
+    LCM,
+    PatternTest,
+    Except,
+    HoldPattern,
+    Rule,
+    RuleDelayed,
+    ReplaceAll,
+    ReplaceRepeated,
+    Function,
+    Slot,
+    CompoundExpression,
+    Clear,
+    Unset,
+    Remove,
+    Attributes,
+    SetAttributes,
+    ClearAttributes,
+    ReleaseHold,
+    Evaluate,
+    IntegerQ,
+    NumberQ,
+    StringQ,
+    SymbolQ,
+    N,
+    Rational,
+    Complex,
+    I,
+    Sqrt,
+    FullForm,
+    InputForm,
+    Block,
+    Module,
+    TagSet,
+    TagSetDelayed,
+    StringJoin,
+    StringLength,
+    StringTake,
+    StringDrop,
+    ToString,
+    ToExpression,
+    Association,
+    Lookup,
+    Missing,
+    Keys,
+    Values,
+    Total,
+    Nest,
+    NestList,
+    Select,
+    EvenQ,
+    OddQ,
+    Positive,
+    Negative,
+    Count,
+    Cases,
+}
 
 #[macro_export]
 macro_rules! sym {
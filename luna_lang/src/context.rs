@@ -1,18 +1,66 @@
 use crate::Symbol;
 use crate::builtins::register_builtins;
-use crate::{Attributes, BuiltinFn, BuiltinFnMut, Expr};
+use crate::{Attributes, BuiltinFn, BuiltinFnMut, DEFAULT_REAL_PRECISION, Expr};
+use crate::{ExprKind, Normal, compare_specificity, sym};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
 
 pub struct Context {
     definitions: HashMap<Symbol, SymbolDefinition>,
     state_version: usize,
+    default_real_precision: u32,
+    recursion_limit: usize,
+    iteration_limit: usize,
+    evaluation_depth: usize,
+    module_number: usize,
+    messages: Vec<Message>,
+    /// The position in `messages` of the first message emitted by the current top-level evaluation.
+    evaluation_messages: usize,
 }
 
+/// A diagnostic message emitted during evaluation, identified by a tag of the form `symbol::name`,
+/// for example `Part::partw`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Message {
+    pub tag: String,
+    pub text: String,
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.tag, self.text)
+    }
+}
+
+/// The context symbols belong to by default. Symbols in this context, or in `System``, are named
+/// without a qualifying context, for example `x` rather than `Global`x`.
+pub const GLOBAL_CONTEXT: &str = "Global`";
+
+/// The context of the builtin symbols.
+pub const SYSTEM_CONTEXT: &str = "System`";
+
+/// The default maximum depth of nested evaluations, see [`Context::recursion_limit`]. This is kept
+/// low enough that reaching it does not overflow a thread with the default stack size of 2 MiB.
+pub const DEFAULT_RECURSION_LIMIT: usize = 128;
+
+/// The default maximum number of times an expression is rewritten, see
+/// [`Context::iteration_limit`].
+pub const DEFAULT_ITERATION_LIMIT: usize = 4096;
+
 impl Context {
     pub fn new() -> Self {
         Self {
             definitions: HashMap::new(),
             state_version: 0,
+            default_real_precision: DEFAULT_REAL_PRECISION,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            iteration_limit: DEFAULT_ITERATION_LIMIT,
+            evaluation_depth: 0,
+            module_number: 0,
+            messages: vec![],
+            evaluation_messages: 0,
         }
     }
 
@@ -20,6 +68,13 @@ impl Context {
         let mut context = Self {
             definitions: HashMap::new(),
             state_version: 0,
+            default_real_precision: DEFAULT_REAL_PRECISION,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            iteration_limit: DEFAULT_ITERATION_LIMIT,
+            evaluation_depth: 0,
+            module_number: 0,
+            messages: vec![],
+            evaluation_messages: 0,
         };
 
         register_builtins(&mut context);
@@ -32,6 +87,77 @@ impl Context {
         self.state_version
     }
 
+    /// The precision, in bits, given to reals which are parsed or numericized without an explicit
+    /// precision.
+    pub fn default_real_precision(&self) -> u32 {
+        self.default_real_precision
+    }
+
+    pub fn set_default_real_precision(&mut self, precision: u32) {
+        self.default_real_precision = precision;
+    }
+
+    /// The maximum depth of nested evaluations, for example those made while checking the
+    /// condition of a definition. Evaluations beyond this depth are returned wrapped in `Hold`.
+    pub fn recursion_limit(&self) -> usize {
+        self.recursion_limit
+    }
+
+    pub fn set_recursion_limit(&mut self, limit: usize) {
+        self.recursion_limit = limit;
+    }
+
+    /// The maximum number of times a single evaluation rewrites an expression, for example by
+    /// applying `f[x_] := f[x]` to `f[1]`. An expression which is still changing after this many
+    /// rewrites is returned wrapped in `Hold`.
+    pub fn iteration_limit(&self) -> usize {
+        self.iteration_limit
+    }
+
+    pub fn set_iteration_limit(&mut self, limit: usize) {
+        self.iteration_limit = limit;
+    }
+
+    /// The number of evaluations currently in progress.
+    pub(crate) fn evaluation_depth(&self) -> usize {
+        self.evaluation_depth
+    }
+
+    pub(crate) fn set_evaluation_depth(&mut self, depth: usize) {
+        self.evaluation_depth = depth;
+    }
+
+    /// Records a diagnostic message, for example a warning that a part does not exist. The message
+    /// does not affect the evaluation, and is kept until taken with [`Context::take_messages`].
+    /// A message identical to one already emitted by the same top-level evaluation is dropped, as
+    /// an expression left unevaluated may be stepped again, emitting its message again.
+    pub fn emit_message(&mut self, tag: impl Into<String>, text: impl Into<String>) {
+        let message = Message {
+            tag: tag.into(),
+            text: text.into(),
+        };
+
+        if !self.messages[self.evaluation_messages..].contains(&message) {
+            self.messages.push(message);
+        }
+    }
+
+    /// Marks the start of a top-level evaluation, after which messages are emitted afresh.
+    pub(crate) fn begin_evaluation_messages(&mut self) {
+        self.evaluation_messages = self.messages.len();
+    }
+
+    /// The messages emitted since they were last taken, oldest first.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Removes and returns the messages emitted since they were last taken, oldest first.
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        self.evaluation_messages = 0;
+        std::mem::take(&mut self.messages)
+    }
+
     pub fn get_definition(&self, symbol: &Symbol) -> Option<&SymbolDefinition> {
         self.definitions.get(&symbol)
     }
@@ -62,13 +188,16 @@ impl Context {
         Ok(())
     }
 
-    pub fn get_values(&self, symbol: &Symbol, value_type: ValueType) -> Option<&SymbolValueSet> {
+    /// Returns the values of the given type defined for the symbol, in the order they are tried.
+    pub fn get_values(&self, symbol: &Symbol, value_type: ValueType) -> Option<&[SymbolValue]> {
         let definition = self.get_definition(symbol)?;
         let values = definition.values(value_type);
 
-        Some(values)
+        Some(values.as_slice())
     }
 
+    /// Adds a value of the given type to the symbol, replacing any existing value with the same
+    /// pattern and condition.
     pub fn set_value(
         &mut self,
         symbol: &Symbol,
@@ -80,8 +209,40 @@ impl Context {
             return Err(format!("Symbol '{}' is read-only", symbol));
         }
 
-        let values = definition.values_mut(value_type);
-        if let Some(_) = values.put(value) {
+        definition.values_mut(value_type).put(value);
+        self.state_version += 1;
+
+        Ok(())
+    }
+
+    pub fn remove_value(
+        &mut self,
+        symbol: &Symbol,
+        value_type: ValueType,
+        pattern: &Expr,
+    ) -> Result<bool, String> {
+        let definition = self.get_definition_mut(symbol);
+        if definition.attributes.read_only() {
+            return Err(format!("Symbol '{}' is read-only", symbol));
+        }
+
+        let removed = definition.values_mut(value_type).remove(pattern);
+        if removed {
+            self.state_version += 1;
+        }
+
+        Ok(removed)
+    }
+
+    pub fn clear_values(&mut self, symbol: &Symbol) -> Result<(), String> {
+        let attributes = self.get_attributes(symbol);
+        if attributes.read_only() {
+            return Err(format!("Symbol {} is read-only", symbol));
+        }
+
+        if let Some(definition) = self.definitions.get_mut(symbol) {
+            *definition = SymbolDefinition::new();
+            definition.set_attributes(attributes);
             self.state_version += 1;
         }
 
@@ -99,17 +260,166 @@ impl Context {
 
         Ok(())
     }
+
+    /// Takes a snapshot of the values and attributes of every symbol, which can later be restored
+    /// to undo any definitions made in the meantime.
+    pub fn snapshot(&self) -> ContextSnapshot {
+        ContextSnapshot {
+            definitions: self.definitions.clone(),
+            symbols: None,
+        }
+    }
+
+    /// Takes a snapshot of the values and attributes of the given symbols only. Restoring it
+    /// leaves any other symbols untouched.
+    pub fn snapshot_symbols(&self, symbols: &[Symbol]) -> ContextSnapshot {
+        let definitions = symbols
+            .iter()
+            .filter_map(|symbol| Some((symbol.clone(), self.get_definition(symbol)?.clone())))
+            .collect();
+
+        ContextSnapshot {
+            definitions,
+            symbols: Some(symbols.into()),
+        }
+    }
+
+    /// Restores the values and attributes of the symbols in the `snapshot` to those they had when
+    /// it was taken.
+    pub fn restore(&mut self, mut snapshot: ContextSnapshot) {
+        match snapshot.symbols {
+            None => self.definitions = snapshot.definitions,
+            Some(symbols) => {
+                for symbol in symbols {
+                    match snapshot.definitions.remove(&symbol) {
+                        Some(definition) => self.definitions.insert(symbol, definition),
+                        None => self.definitions.remove(&symbol),
+                    };
+                }
+            }
+        }
+
+        self.state_version += 1;
+    }
+
+    /// The context new symbols are created in, given by the value of `$Context`.
+    pub fn current_context(&self) -> String {
+        self.own_value(&Symbol::new("$Context"))
+            .and_then(|value| value.try_string().cloned())
+            .unwrap_or_else(|| GLOBAL_CONTEXT.to_owned())
+    }
+
+    /// The contexts searched for symbols which are named without a context, after the current
+    /// context, given by the value of `$ContextPath`.
+    pub fn context_path(&self) -> Vec<String> {
+        let Some(path) = self.own_value(&Symbol::new("$ContextPath")) else {
+            return vec![SYSTEM_CONTEXT.to_owned(), GLOBAL_CONTEXT.to_owned()];
+        };
+
+        path.try_normal_head(&sym!(List))
+            .map(|list| {
+                list.elements()
+                    .iter()
+                    .filter_map(|context| context.try_string().cloned())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Resolves a symbol name to the symbol it refers to. A qualified name such as `Private`x` is
+    /// used as given. Otherwise the current context and then each context on the context path are
+    /// searched for an existing symbol with the name, and if there is none the symbol is created in
+    /// the current context.
+    pub fn resolve_symbol(&self, name: &str) -> Symbol {
+        if let Some((context, name)) = name.rsplit_once('`') {
+            return qualify_symbol(&format!("{}`", context), name);
+        }
+
+        let current_context = self.current_context();
+
+        std::iter::once(current_context.clone())
+            .chain(self.context_path())
+            .map(|context| qualify_symbol(&context, name))
+            .find(|symbol| self.get_definition(symbol).is_some())
+            .unwrap_or_else(|| qualify_symbol(&current_context, name))
+    }
+
+    /// Resolves every symbol within the expression, see [`Context::resolve_symbol`].
+    pub fn resolve_symbols(&self, expr: &Expr) -> Expr {
+        match expr.kind() {
+            ExprKind::Symbol(symbol) => Expr::from(self.resolve_symbol(symbol.as_str())),
+            ExprKind::Normal(normal) => Expr::from(Normal::new(
+                self.resolve_symbols(normal.head()),
+                normal
+                    .elements()
+                    .iter()
+                    .map(|elem| self.resolve_symbols(elem))
+                    .collect::<Vec<_>>(),
+            )),
+            _ => expr.clone(),
+        }
+    }
+
+    /// Returns the value the symbol evaluates to, if it has a single unconditional own value.
+    fn own_value(&self, symbol: &Symbol) -> Option<&Expr> {
+        match self.get_values(symbol, ValueType::OwnValue)? {
+            [
+                SymbolValue::Definitions {
+                    condition: None,
+                    ground,
+                    ..
+                },
+            ] => Some(ground),
+            _ => None,
+        }
+    }
+
+    /// Returns a number which has not been returned before, used to give the local variables of
+    /// each `Module` unique names.
+    pub(crate) fn next_module_number(&mut self) -> usize {
+        self.module_number += 1;
+        self.module_number
+    }
 }
 
+/// Returns the symbol with the given name in the given context.
+fn qualify_symbol(context: &str, name: &str) -> Symbol {
+    if context == GLOBAL_CONTEXT || context == SYSTEM_CONTEXT {
+        Symbol::new(name)
+    } else {
+        Symbol::new(&format!("{}{}", context, name))
+    }
+}
+
+/// The values and attributes of the symbols in a [`Context`] at some point in time, see
+/// [`Context::snapshot`] and [`Context::snapshot_symbols`].
+#[derive(Clone)]
+pub struct ContextSnapshot {
+    definitions: HashMap<Symbol, SymbolDefinition>,
+
+    /// The symbols the snapshot was taken of, or `None` if it was taken of every symbol.
+    symbols: Option<Box<[Symbol]>>,
+}
+
+/// The kinds of values which can be attached to a symbol, see [`SymbolDefinition`].
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum ValueType {
+    /// Applies to the symbol alone, for example `x = 1`.
     OwnValue,
+
+    /// Applies to expressions with the symbol as an element, or as the head of an element, for
+    /// example `g /: f[g[x_]] := x`.
     UpValue,
+
+    /// Applies to expressions with the symbol as their head, for example `f[x_] := x`.
     DownValue,
+
+    /// Applies to expressions whose head has the symbol as its head, for example `f[n_][x_] := x`.
     SubValue,
 }
 
 /// A `SymbolDefinition` contains all the transformation rules that apply to a given symbol.
+#[derive(Clone)]
 pub struct SymbolDefinition {
     /// Symbol attributes.
     attributes: Attributes,
@@ -209,7 +519,9 @@ impl SymbolValue {
     }
 }
 
-/// A `SymbolValueSet` is a set of `SymbolValue`s.
+/// A `SymbolValueSet` is a set of `SymbolValue`s, ordered so that values with more specific
+/// patterns are tried first.
+#[derive(Clone)]
 pub struct SymbolValueSet(Vec<SymbolValue>);
 
 impl SymbolValueSet {
@@ -224,9 +536,29 @@ impl SymbolValueSet {
             }
         }
 
-        self.0.push(value);
+        // New values go before the first existing value which is less specific, so values which
+        // cannot be told apart are tried in the order they were defined.
+        let index = self
+            .0
+            .iter()
+            .position(|existing| {
+                compare_specificity(value.pattern(), existing.pattern()) == Ordering::Less
+            })
+            .unwrap_or(self.0.len());
+
+        self.0.insert(index, value);
         None
     }
+
+    pub fn as_slice(&self) -> &[SymbolValue] {
+        &self.0
+    }
+
+    pub fn remove(&mut self, pattern: &Expr) -> bool {
+        let len = self.0.len();
+        self.0.retain(|existing| existing.pattern() != pattern);
+        self.0.len() != len
+    }
 }
 
 impl<'a> IntoIterator for &'a SymbolValueSet {
@@ -237,3 +569,205 @@ impl<'a> IntoIterator for &'a SymbolValueSet {
         (&self.0).into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Attribute, Attributes, Context, Symbol, SymbolValue, ValueType, parse_str};
+
+    fn definition(pattern: &str, ground: &str) -> SymbolValue {
+        SymbolValue::Definitions {
+            pattern: parse_str(pattern).unwrap(),
+            condition: None,
+            ground: parse_str(ground).unwrap(),
+        }
+    }
+
+    #[test]
+    fn down_value_round_trip() {
+        let mut context = Context::new();
+        let f = Symbol::new("f");
+
+        context
+            .set_value(&f, ValueType::DownValue, definition("f[x_]", "x + 1"))
+            .unwrap();
+
+        let values = context.get_values(&f, ValueType::DownValue).unwrap();
+
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].pattern(), &parse_str("f[x_]").unwrap());
+        assert!(matches!(
+            &values[0],
+            SymbolValue::Definitions { ground, .. } if *ground == parse_str("x + 1").unwrap()
+        ));
+    }
+
+    #[test]
+    fn value_types_are_separate() {
+        let mut context = Context::new();
+        let f = Symbol::new("f");
+
+        context
+            .set_value(&f, ValueType::DownValue, definition("f[x_]", "x"))
+            .unwrap();
+
+        assert!(
+            context
+                .get_values(&f, ValueType::OwnValue)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            context
+                .get_values(&f, ValueType::UpValue)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            context
+                .get_values(&f, ValueType::SubValue)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn undefined_symbol() {
+        let context = Context::new();
+
+        assert!(
+            context
+                .get_values(&Symbol::new("f"), ValueType::DownValue)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn same_pattern_replaces() {
+        let mut context = Context::new();
+        let f = Symbol::new("f");
+
+        context
+            .set_value(&f, ValueType::DownValue, definition("f[x_]", "1"))
+            .unwrap();
+        context
+            .set_value(&f, ValueType::DownValue, definition("f[x_]", "2"))
+            .unwrap();
+
+        let values = context.get_values(&f, ValueType::DownValue).unwrap();
+
+        assert_eq!(values.len(), 1);
+        assert!(matches!(
+            &values[0],
+            SymbolValue::Definitions { ground, .. } if *ground == parse_str("2").unwrap()
+        ));
+    }
+
+    #[test]
+    fn setting_changes_state_version() {
+        let mut context = Context::new();
+        let f = Symbol::new("f");
+
+        let state_version = context.state_version();
+        context
+            .set_value(&f, ValueType::DownValue, definition("f[x_]", "1"))
+            .unwrap();
+        assert_ne!(context.state_version(), state_version);
+
+        let state_version = context.state_version();
+        context
+            .set_value(&f, ValueType::DownValue, definition("f[x_]", "2"))
+            .unwrap();
+        assert_ne!(context.state_version(), state_version);
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        let mut context = Context::new();
+        let f = Symbol::new("f");
+        let g = Symbol::new("g");
+
+        context
+            .set_value(&f, ValueType::DownValue, definition("f[x_]", "1"))
+            .unwrap();
+
+        let snapshot = context.snapshot();
+
+        context
+            .set_value(&f, ValueType::DownValue, definition("f[x_]", "2"))
+            .unwrap();
+        context
+            .set_value(&g, ValueType::OwnValue, definition("g", "3"))
+            .unwrap();
+        context
+            .set_attributes(&f, Attributes::from(Attribute::HoldAll))
+            .unwrap();
+
+        let state_version = context.state_version();
+        context.restore(snapshot);
+
+        let values = context.get_values(&f, ValueType::DownValue).unwrap();
+        assert_eq!(values.len(), 1);
+        assert!(matches!(
+            &values[0],
+            SymbolValue::Definitions { ground, .. } if *ground == parse_str("1").unwrap()
+        ));
+
+        assert!(context.get_values(&g, ValueType::OwnValue).is_none());
+        assert_eq!(context.get_attributes(&f), Attributes::empty());
+        assert_ne!(context.state_version(), state_version);
+    }
+
+    #[test]
+    fn snapshot_is_reusable() {
+        let mut context = Context::new();
+        let f = Symbol::new("f");
+
+        let snapshot = context.snapshot();
+
+        for ground in ["1", "2"] {
+            context
+                .set_value(&f, ValueType::OwnValue, definition("f", ground))
+                .unwrap();
+
+            context.restore(snapshot.clone());
+
+            assert!(context.get_values(&f, ValueType::OwnValue).is_none());
+        }
+    }
+
+    #[test]
+    fn snapshot_symbols_restore() {
+        let mut context = Context::new();
+        let f = Symbol::new("f");
+        let g = Symbol::new("g");
+        let h = Symbol::new("h");
+
+        context
+            .set_value(&f, ValueType::OwnValue, definition("f", "1"))
+            .unwrap();
+
+        let snapshot = context.snapshot_symbols(&[f.clone(), g.clone()]);
+
+        context
+            .set_value(&f, ValueType::OwnValue, definition("f", "2"))
+            .unwrap();
+        context
+            .set_value(&g, ValueType::OwnValue, definition("g", "2"))
+            .unwrap();
+        context
+            .set_value(&h, ValueType::OwnValue, definition("h", "2"))
+            .unwrap();
+
+        context.restore(snapshot);
+
+        assert!(matches!(
+            &context.get_values(&f, ValueType::OwnValue).unwrap()[0],
+            SymbolValue::Definitions { ground, .. } if *ground == parse_str("1").unwrap()
+        ));
+        assert!(context.get_values(&g, ValueType::OwnValue).is_none());
+        assert_eq!(
+            context.get_values(&h, ValueType::OwnValue).unwrap().len(),
+            1
+        );
+    }
+}
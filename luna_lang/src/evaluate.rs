@@ -1,7 +1,9 @@
 use crate::{
     Attributes, Context, Expr, ExprKind, Matcher, Normal, SolutionSet, Symbol, SymbolValue,
-    ValueType, try_sequence,
+    ValueType, sym, try_sequence,
 };
+use std::fmt;
+use std::fmt::Formatter;
 
 pub enum EvalResult {
     Changed(Expr),
@@ -26,128 +28,460 @@ impl EvalResult {
     }
 }
 
-pub fn evaluate(expr: Expr, context: &mut Context) -> Expr {
-    let initial_context_state = context.state_version();
+/// An error which stops an evaluation. The context is left usable, with the effects of anything
+/// evaluated before the error still in place.
+#[derive(Clone, PartialEq, Debug)]
+pub enum EvalError {
+    /// A definition was made for, or removed from, a symbol with the `ReadOnly` attribute.
+    ReadOnly(Symbol),
+    /// A definition was made for an expression which cannot be assigned to, such as `5 = x`.
+    InvalidAssignment(Expr),
+}
 
-    match evaluate_step(expr, context) {
-        EvalResult::Changed(new_expr) => evaluate(new_expr, context),
-        EvalResult::Unchanged(new_expr) => {
-            if initial_context_state != context.state_version() {
-                evaluate(new_expr, context)
-            } else {
-                new_expr
-            }
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::ReadOnly(symbol) => write!(f, "Symbol '{}' is read-only", symbol),
+            EvalError::InvalidAssignment(expr) => write!(f, "Cannot assign to '{}'", expr),
         }
     }
 }
 
-pub fn evaluate_step(expr: Expr, context: &mut Context) -> EvalResult {
-    match expr.kind() {
-        ExprKind::Symbol(symbol) => {
-            match find_matching_definition(&expr, symbol, ValueType::OwnValue, context) {
-                None => EvalResult::Unchanged(expr),
-                Some(unevaluated_rule) => unevaluated_rule.apply(expr, context),
+impl std::error::Error for EvalError {}
+
+/// Evaluates the expression until it no longer changes. Runaway evaluations are stopped by the
+/// recursion and iteration limits of the context, in which case the partially evaluated
+/// expression is returned wrapped in `Hold`. Fails if any part of the evaluation fails, such as an
+/// assignment to a literal.
+pub fn evaluate(expr: Expr, context: &mut Context) -> Result<Expr, EvalError> {
+    let depth = context.evaluation_depth();
+    if depth >= context.recursion_limit() {
+        return Ok(Expr::from(Normal::new(sym!(Hold), vec![expr])));
+    }
+
+    if depth == 0 {
+        context.begin_evaluation_messages();
+    }
+
+    context.set_evaluation_depth(depth + 1);
+    let result = evaluate_to_fixed_point(expr, context);
+    context.set_evaluation_depth(depth);
+
+    result
+}
+
+fn evaluate_to_fixed_point(mut expr: Expr, context: &mut Context) -> Result<Expr, EvalError> {
+    for _ in 0..context.iteration_limit() {
+        let initial_context_state = context.state_version();
+
+        match evaluate_step(expr, context)? {
+            EvalResult::Changed(new_expr) => expr = new_expr,
+            EvalResult::Unchanged(new_expr) => {
+                if initial_context_state == context.state_version() {
+                    return Ok(new_expr);
+                }
+
+                expr = new_expr;
             }
         }
-        ExprKind::Normal(normal) => {
-            let mut changed = false;
+    }
+
+    Ok(Expr::from(Normal::new(sym!(Hold), vec![expr])))
+}
+
+pub fn evaluate_step(expr: Expr, context: &mut Context) -> Result<EvalResult, EvalError> {
+    // Normal expressions are stepped after their head and elements. Rather than recursing, the
+    // expressions being stepped are kept on an explicit stack so that deeply nested expressions do
+    // not overflow the native stack.
+    let mut frames: Vec<StepFrame> = vec![];
+    let mut next = expr;
 
-            let head_eval = match evaluate_step(normal.head().clone(), context) {
-                EvalResult::Changed(new_expr) => {
-                    changed = true;
-                    new_expr
+    loop {
+        // Descend through the heads of normal expressions until an atom is reached.
+        let mut result = match next.kind() {
+            ExprKind::Normal(normal) => {
+                let head = normal.head().clone();
+                frames.push(StepFrame::new(next));
+                next = head;
+                continue;
+            }
+            ExprKind::Symbol(symbol) => {
+                match find_matching_definition(&next, symbol, ValueType::OwnValue, context) {
+                    None => EvalResult::Unchanged(next),
+                    Some(unevaluated_rule) => unevaluated_rule.apply(next, context)?,
                 }
-                EvalResult::Unchanged(new_expr) => new_expr,
-            };
+            }
+            _ => EvalResult::Unchanged(next),
+        };
 
-            let attributes = match head_eval.name() {
-                None => Attributes::empty(),
-                Some(name) => context.get_attributes(&name),
+        // Pass the result up to the enclosing expressions, until one has an element to step.
+        loop {
+            let Some(frame) = frames.last_mut() else {
+                return Ok(result);
             };
 
-            let mut elements_eval = normal
-                .elements()
-                .iter()
-                .enumerate()
-                .map(|(i, elem)| {
-                    if attributes.hold_all()
-                        || attributes.hold_all_complete()
-                        || (attributes.hold_first() && i == 0)
-                        || (attributes.hold_rest() && i > 0)
-                    {
-                        return elem.clone();
-                    }
+            frame.push(result, context);
 
-                    let result = evaluate_step(elem.clone(), context);
-                    changed |= result.is_changed();
-                    result.into_expr()
-                })
-                .collect::<Vec<_>>();
+            if let Some(elem) = frame.next_element(context)? {
+                next = elem;
+                break;
+            }
+
+            result = frames.pop().unwrap().finish(context)?;
+        }
+    }
+}
 
-            if !attributes.hold_all_complete() {
-                if !attributes.hold_sequences() {
-                    elements_eval = elements_eval
-                        .into_iter()
-                        .flat_map(|elem| match try_sequence(&elem) {
-                            None => vec![elem],
-                            Some(seq) => {
-                                changed = true;
-                                seq.to_vec()
-                            }
-                        })
-                        .collect();
+/// A normal expression being stepped by [`evaluate_step`], along with its stepped head and those
+/// elements which have been stepped so far.
+struct StepFrame {
+    expr: Expr,
+    head_eval: Option<Expr>,
+    attributes: Attributes,
+    elements_eval: Vec<Expr>,
+    changed: bool,
+}
+
+impl StepFrame {
+    fn new(expr: Expr) -> Self {
+        let len = expr.try_normal().map_or(0, Normal::len);
+
+        Self {
+            expr,
+            head_eval: None,
+            attributes: Attributes::empty(),
+            elements_eval: Vec::with_capacity(len),
+            changed: false,
+        }
+    }
+
+    fn elements(&self) -> &[Expr] {
+        self.expr.try_normal().unwrap().elements()
+    }
+
+    /// Records the result of stepping the head, or otherwise the next element.
+    fn push(&mut self, result: EvalResult, context: &Context) {
+        self.changed |= result.is_changed();
+
+        if self.head_eval.is_some() {
+            self.elements_eval.push(result.into_expr());
+            return;
+        }
+
+        let head_eval = result.into_expr();
+
+        // Only symbol heads carry attributes, so `f[x][y]` is unaffected by those of `f`.
+        self.attributes = match head_eval.try_symbol() {
+            None => Attributes::empty(),
+            Some(name) => context.get_attributes(name),
+        };
+
+        self.head_eval = Some(head_eval);
+    }
+
+    /// Returns the next element which needs to be stepped, skipping over any held elements.
+    fn next_element(&mut self, context: &mut Context) -> Result<Option<Expr>, EvalError> {
+        let attributes = self.attributes;
+
+        while let Some(elem) = self.elements().get(self.elements_eval.len()).cloned() {
+            let i = self.elements_eval.len();
+
+            if attributes.hold_all_complete() {
+                self.elements_eval.push(elem);
+                continue;
+            }
+
+            if attributes.hold_all()
+                || (attributes.hold_first() && i == 0)
+                || (attributes.hold_rest() && i > 0)
+            {
+                // Held arguments are still evaluated when wrapped in `Evaluate`.
+                if !elem.is_normal_head(&sym!(Evaluate)) {
+                    self.elements_eval.push(elem);
+                    continue;
                 }
 
-                //   * strip the outermost of any Unevaluated wrappers that appear among the ei.
+                let result = evaluate(elem.clone(), context)?;
+                self.changed |= result != elem;
+                self.elements_eval.push(result);
+                continue;
             }
 
-            //   * If h has attribute Flat, then flatten out all nested expressions with head h.
-            //   * If h has attribute Listable, then thread through any ei that are lists.
-            //   * If h has attribute Orderless, then sort the ei into order
+            return Ok(Some(elem));
+        }
 
-            //   * Unless h has attribute HoldAllComplete, use any applicable transformation rules associated with f that you have defined for objects of the form h[f[e1,…],…].
-            //   * Use any built‐in transformation rules associated with f for objects of the form h[f[e1,…],…].
+        Ok(None)
+    }
 
-            let new_expr = Expr::from(Normal::new(head_eval, elements_eval));
+    /// Steps the expression itself, once its head and elements have been stepped.
+    fn finish(self, context: &mut Context) -> Result<EvalResult, EvalError> {
+        let Self {
+            expr,
+            head_eval,
+            attributes,
+            mut elements_eval,
+            mut changed,
+        } = self;
 
-            if changed {
-                return EvalResult::Changed(new_expr);
-            }
+        let head_eval = head_eval.unwrap();
 
-            match new_expr.name() {
-                None => EvalResult::Unchanged(new_expr),
-                Some(name) => {
-                    match find_matching_definition(&new_expr, &name, ValueType::DownValue, context)
-                    {
-                        None => EvalResult::Unchanged(expr),
-                        Some(unevaluated_rule) => unevaluated_rule.apply(expr, context),
+        if !attributes.hold_all_complete() && !attributes.hold_sequences() {
+            elements_eval = elements_eval
+                .into_iter()
+                .flat_map(|elem| match try_sequence(&elem) {
+                    None => vec![elem],
+                    Some(seq) => {
+                        changed = true;
+                        seq.to_vec()
                     }
-                }
+                })
+                .collect();
+        }
+
+        //   * Unless h has attribute HoldAllComplete, strip the outermost of any Unevaluated
+        //     wrappers that appear among the ei.
+
+        //   * If h has attribute Flat, then flatten out all nested expressions with head h.
+        if attributes.associative() {
+            elements_eval = elements_eval
+                .into_iter()
+                .flat_map(|elem| match elem.try_normal() {
+                    Some(nested) if *nested.head() == head_eval => {
+                        changed = true;
+                        nested.elements().to_vec()
+                    }
+                    _ => vec![elem],
+                })
+                .collect();
+        }
+
+        //   * If h has attribute OneIdentity, then collapse h[e] into e.
+        if attributes.one_identity() && elements_eval.len() == 1 {
+            return Ok(EvalResult::Changed(elements_eval.swap_remove(0)));
+        }
+
+        //   * If h has attribute Listable, then thread through any ei that are lists.
+        if attributes.listable()
+            && let Some(threaded) = thread_listable(&head_eval, &elements_eval)
+        {
+            return Ok(EvalResult::Changed(threaded));
+        }
+
+        //   * If h has attribute Orderless, then sort the ei into order
+        if attributes.commutative() && !elements_eval.is_sorted() {
+            elements_eval.sort();
+            changed = true;
+        }
+
+        let new_expr = Expr::from(Normal::new(head_eval, elements_eval));
+
+        if changed {
+            return Ok(EvalResult::Changed(new_expr));
+        }
+
+        // Pure functions are applied by substituting the arguments into the body.
+        if let Some(function) = new_expr.head().try_normal_head(&sym!(Function))
+            && let Some(result) = apply_function(function, new_expr.try_normal().unwrap())
+        {
+            return Ok(EvalResult::Changed(result));
+        }
+
+        // Up-values of the elements are applied before the down-values of the head, for example
+        // `g /: f[g[x_]] := x` applies to `f[g[1]]` even if `f` has its own definitions.
+        if !attributes.hold_all_complete()
+            && let Some(unevaluated_rule) = find_matching_up_value(&new_expr, context)
+        {
+            return unevaluated_rule.apply(expr, context);
+        }
+
+        // Expressions such as `f[x][y]` are defined by the sub-values of the innermost head `f`.
+        let value_type = if new_expr.head().try_normal().is_some() {
+            ValueType::SubValue
+        } else {
+            ValueType::DownValue
+        };
+
+        match new_expr.name() {
+            None => Ok(EvalResult::Unchanged(new_expr)),
+            Some(name) => match find_matching_definition(&new_expr, name, value_type, context) {
+                None => Ok(EvalResult::Unchanged(expr)),
+                Some(unevaluated_rule) => unevaluated_rule.apply(expr, context),
+            },
+        }
+    }
+}
+
+/// Applies a pure function to the elements of `application`. For `Function[body]` each `Slot[n]`
+/// within the body is replaced with the `n`th element, whereas for `Function[{x, ...}, body]` each
+/// formal parameter is replaced with the element at the same position. Returns `None` if the
+/// function is malformed or there are too few elements.
+fn apply_function(function: &Normal, application: &Normal) -> Option<Expr> {
+    match function.elements() {
+        [body] => substitute_slots(body, application.elements()),
+        [parameters, body] => {
+            let parameters = match parameters.try_normal_head(&sym!(List)) {
+                Some(list) => list.elements(),
+                None => std::slice::from_ref(parameters),
+            };
+
+            if parameters.len() > application.len() {
+                return None;
+            }
+
+            let bindings = parameters
+                .iter()
+                .zip(application.elements())
+                .map(|(parameter, argument)| {
+                    Some((parameter.try_symbol()?.clone(), argument.clone()))
+                })
+                .collect::<Option<SolutionSet>>()?;
+
+            Some(replace_all(&bindings, body.clone()).into_expr())
+        }
+        _ => None,
+    }
+}
+
+/// Replaces each `Slot[n]` within `expr` with the `n`th of the `arguments`. Slots within nested
+/// pure functions belong to those functions, so are left untouched.
+fn substitute_slots(expr: &Expr, arguments: &[Expr]) -> Option<Expr> {
+    let Some(normal) = expr.try_normal() else {
+        return Some(expr.clone());
+    };
+
+    if let Some(slot) = expr.try_normal_head(&sym!(Slot)) {
+        let idx = slot.element(0)?.try_integer()?.to_usize()?;
+
+        return match idx {
+            0 => None,
+            _ => arguments.get(idx - 1).cloned(),
+        };
+    }
+
+    if expr.is_normal_head(&sym!(Function)) {
+        return Some(expr.clone());
+    }
+
+    let head = substitute_slots(normal.head(), arguments)?;
+    let elements = normal
+        .elements()
+        .iter()
+        .map(|elem| substitute_slots(elem, arguments))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Expr::from(Normal::new(head, elements)))
+}
+
+/// Threads `head` over any `List` elements, producing a `List` of applications. Non-list elements
+/// are repeated within each application. Returns `None` if there are no lists to thread over, or
+/// if the lists are not all of the same length.
+fn thread_listable(head: &Expr, elements: &[Expr]) -> Option<Expr> {
+    let mut len = None;
+
+    for elem in elements {
+        if let Some(list) = elem.try_normal_head(&sym!(List)) {
+            match len {
+                None => len = Some(list.len()),
+                Some(len) if len != list.len() => return None,
+                Some(_) => {}
             }
         }
-        _ => EvalResult::Unchanged(expr),
     }
+
+    let threaded = (0..len?)
+        .map(|idx| {
+            let args = elements
+                .iter()
+                .map(|elem| match elem.try_normal_head(&sym!(List)) {
+                    Some(list) => list.elements()[idx].clone(),
+                    None => elem.clone(),
+                })
+                .collect::<Vec<_>>();
+
+            Expr::from(Normal::new(head.clone(), args))
+        })
+        .collect::<Vec<_>>();
+
+    Some(Expr::from(Normal::new(sym!(List), threaded)))
 }
 
 /// Replaces all instances of a variable with the value that it has been bound to, determined by
 /// symbol name.
 pub fn replace_all(bindings: &SolutionSet, expr: Expr) -> EvalResult {
-    match expr.kind() {
-        ExprKind::Symbol(symbol) => {
-            if let Some(substitution) = bindings.get(symbol) {
-                return EvalResult::Changed(substitution.clone());
+    // As with `evaluate_step`, normal expressions are rebuilt from an explicit stack of the
+    // expressions being visited, holding the replaced head followed by the replaced elements.
+    let mut frames: Vec<(Expr, Vec<Expr>, bool)> = vec![];
+    let mut next = expr;
+
+    loop {
+        let mut result = match next.kind() {
+            ExprKind::Symbol(symbol) => match bindings.get(symbol) {
+                Some(substitution) => EvalResult::Changed(substitution.clone()),
+                None => EvalResult::Unchanged(next),
+            },
+            ExprKind::Normal(normal) => {
+                let head = normal.head().clone();
+                let parts = Vec::with_capacity(normal.len() + 1);
+
+                frames.push((next, parts, false));
+                next = head;
+                continue;
+            }
+            _ => EvalResult::Unchanged(next),
+        };
+
+        loop {
+            let Some((expr, parts, changed)) = frames.last_mut() else {
+                return result;
+            };
+
+            *changed |= result.is_changed();
+            parts.push(result.into_expr());
+
+            if let Some(elem) = expr.try_normal().unwrap().element(parts.len() - 1) {
+                next = elem.clone();
+                break;
             }
 
-            EvalResult::Unchanged(expr)
+            let (expr, mut parts, changed) = frames.pop().unwrap();
+
+            result = if changed {
+                let head = parts.remove(0);
+                EvalResult::Changed(Expr::from(Normal::new(head, parts)))
+            } else {
+                EvalResult::Unchanged(expr)
+            };
         }
+    }
+}
+
+impl Expr {
+    /// Replaces each part of the expression matching the left hand side of one of the `rules` with
+    /// the corresponding right hand side, substituting in any bindings. Parts are visited from the
+    /// outside in, and the first matching rule is used. Replaced parts are not visited again.
+    pub fn replace_all(&self, rules: &[(Expr, Expr)], context: &mut Context) -> Expr {
+        apply_rules(rules, self.clone(), context).into_expr()
+    }
+}
+
+/// Applies the first of the `rules` matching `expr`, otherwise applies the rules to the head and
+/// elements of `expr`.
+fn apply_rules(rules: &[(Expr, Expr)], expr: Expr, context: &mut Context) -> EvalResult {
+    for (lhs, rhs) in rules {
+        if let Some(bindings) = Matcher::new(lhs.clone(), expr.clone(), context).first() {
+            return EvalResult::Changed(replace_all(&bindings, rhs.clone()).into_expr());
+        }
+    }
+
+    match expr.kind() {
         ExprKind::Normal(normal) => {
-            let head = replace_all(bindings, normal.head().clone());
+            let head = apply_rules(rules, normal.head().clone(), context);
 
             let mut changed = head.is_changed();
             let mut elements = Vec::with_capacity(normal.elements().len());
 
             for elem in normal.elements() {
-                let result = replace_all(bindings, elem.clone());
+                let result = apply_rules(rules, elem.clone(), context);
                 changed |= result.is_changed();
                 elements.push(result.into_expr())
             }
@@ -162,16 +496,31 @@ pub fn replace_all(bindings: &SolutionSet, expr: Expr) -> EvalResult {
     }
 }
 
+/// Substitutes the `bindings` into `condition` and evaluates it, returning whether the condition
+/// evaluated to `True`. A condition which fails to evaluate is not satisfied.
+pub(crate) fn check_condition(
+    condition: &Expr,
+    bindings: &SolutionSet,
+    context: &mut Context,
+) -> bool {
+    let condition = replace_all(bindings, condition.clone()).into_expr();
+
+    evaluate(condition, context).is_ok_and(|result| result.try_symbol() == Some(&sym!(True)))
+}
+
 struct UnevaluatedRule {
     value: SymbolValue,
     bindings: SolutionSet,
 }
 
 impl UnevaluatedRule {
-    pub fn apply(self, expr: Expr, context: &mut Context) -> EvalResult {
+    pub fn apply(self, expr: Expr, context: &mut Context) -> Result<EvalResult, EvalError> {
         match self.value {
-            SymbolValue::Definitions { ground, .. } => replace_all(&self.bindings, ground),
-            SymbolValue::BuiltIn { built_in, .. } => built_in(self.bindings, expr, context),
+            // Applying a definition is always a change, even if no variables were substituted.
+            SymbolValue::Definitions { ground, .. } => Ok(EvalResult::Changed(
+                replace_all(&self.bindings, ground).into_expr(),
+            )),
+            SymbolValue::BuiltIn { built_in, .. } => Ok(built_in(self.bindings, expr, context)),
             SymbolValue::BuiltInMut { built_in, .. } => built_in(self.bindings, expr, context),
         }
     }
@@ -185,25 +534,582 @@ fn find_matching_definition(
     value_type: ValueType,
     context: &mut Context,
 ) -> Option<UnevaluatedRule> {
-    let values = context.get_values(symbol, value_type)?;
+    // The values are cloned, as evaluating conditions requires mutable access to the context.
+    let values = context.get_values(symbol, value_type)?.to_vec();
 
-    for value in values {
+    for value in &values {
         let mut matcher = Matcher::new(value.pattern().clone(), ground.clone(), context);
 
-        while let Some(bindings) = matcher.next() {
-            if let Some(condition) = value.condition() {
-                todo!();
-                // if check_condition(c.clone(), &substitutions, context) {
-                //   return wrap_definition_match(&symbol_value, substitutions);
-                // }
-            } else {
-                return Some(UnevaluatedRule {
-                    value: value.clone(),
-                    bindings,
-                });
-            }
+        if let Some(condition) = value.condition() {
+            matcher = matcher.with_condition(condition.clone());
+        }
+
+        if let Some(bindings) = matcher.first() {
+            return Some(UnevaluatedRule {
+                value: value.clone(),
+                bindings,
+            });
         }
     }
 
     None
 }
+
+/// Searches the up-values of the symbols which appear as elements of `ground`, or as the heads of
+/// its elements, for one matching `ground`. The symbols are searched in the order they appear.
+fn find_matching_up_value(ground: &Expr, context: &mut Context) -> Option<UnevaluatedRule> {
+    let mut symbols: Vec<&Symbol> = vec![];
+
+    for elem in ground.try_normal()?.elements() {
+        if let Some(name) = elem.name()
+            && !symbols.contains(&name)
+        {
+            symbols.push(name);
+        }
+    }
+
+    symbols
+        .into_iter()
+        .find_map(|symbol| find_matching_definition(ground, symbol, ValueType::UpValue, context))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Attribute, Attributes, parse_str};
+
+    fn create_context() -> Context {
+        let mut context = Context::new_global_context();
+
+        context
+            .set_attributes(
+                &Symbol::new("fs"),
+                Attributes::from(Attribute::HoldSequences),
+            )
+            .unwrap();
+
+        context
+            .set_attributes(&Symbol::new("fa"), Attributes::from(Attribute::Associative))
+            .unwrap();
+
+        context
+            .set_attributes(&Symbol::new("fc"), Attributes::from(Attribute::Commutative))
+            .unwrap();
+
+        context
+            .set_attributes(&Symbol::new("fl"), Attributes::from(Attribute::Listable))
+            .unwrap();
+
+        context
+            .set_attributes(&Symbol::new("fo"), Attributes::from(Attribute::OneIdentity))
+            .unwrap();
+
+        context
+            .set_attributes(
+                &Symbol::new("fhc"),
+                Attributes::from(Attribute::HoldAllComplete),
+            )
+            .unwrap();
+
+        context
+    }
+
+    macro_rules! attributes_test {
+        ($name:ident, $input:expr, $expected:expr) => {
+            #[test]
+            fn $name() -> () {
+                let mut context = create_context();
+
+                let result = evaluate(parse_str($input).unwrap(), &mut context).unwrap();
+
+                assert_eq!(result, parse_str($expected).unwrap());
+            }
+        };
+    }
+
+    mod sequences {
+        use super::*;
+
+        attributes_test!(splices, "f[a, Sequence[b, c], d]", "f[a, b, c, d]");
+        attributes_test!(splices_empty, "f[a, Sequence[], d]", "f[a, d]");
+        attributes_test!(splices_nested, "f[Sequence[a, Sequence[b]]]", "f[a, b]");
+        attributes_test!(
+            hold_sequences,
+            "fs[a, Sequence[b, c], d]",
+            "fs[a, Sequence[b, c], d]"
+        );
+        attributes_test!(
+            hold_all_complete,
+            "fhc[a, Sequence[b, c], d]",
+            "fhc[a, Sequence[b, c], d]"
+        );
+    }
+
+    mod associative {
+        use super::*;
+
+        attributes_test!(flattens, "fa[a, fa[b, c], d]", "fa[a, b, c, d]");
+        attributes_test!(flattens_deeply, "fa[a, fa[b, fa[c, d]]]", "fa[a, b, c, d]");
+        attributes_test!(flattens_empty, "fa[a, fa[], b]", "fa[a, b]");
+        attributes_test!(other_heads, "fa[a, f[b, c]]", "fa[a, f[b, c]]");
+        attributes_test!(non_associative, "f[a, f[b, c]]", "f[a, f[b, c]]");
+        attributes_test!(plus, "Plus[a, Plus[b, c]]", "Plus[a, b, c]");
+        attributes_test!(plus_numeric, "Plus[1, Plus[x, 2]]", "Plus[3, x]");
+    }
+
+    mod commutative {
+        use super::*;
+
+        attributes_test!(sorts, "fc[c, a, b]", "fc[a, b, c]");
+        attributes_test!(sorted, "fc[a, b, c]", "fc[a, b, c]");
+        attributes_test!(sorts_kinds, "fc[x, 2.5, 1, \"s\"]", "fc[\"s\", 1, 2.5, x]");
+        attributes_test!(non_commutative, "f[b, a]", "f[b, a]");
+        attributes_test!(plus, "Plus[b, a]", "Plus[a, b]");
+        attributes_test!(plus_numeric, "Plus[b, 1, a, 2]", "Plus[3, a, b]");
+    }
+
+    mod listable {
+        use super::*;
+
+        attributes_test!(threads, "fl[{1, 2}, {3, 4}]", "{fl[1, 3], fl[2, 4]}");
+        attributes_test!(broadcasts, "fl[{1, 2}, 10]", "{fl[1, 10], fl[2, 10]}");
+        attributes_test!(single_list, "fl[{a, b, c}]", "{fl[a], fl[b], fl[c]}");
+        attributes_test!(empty_list, "fl[{}, x]", "{}");
+        attributes_test!(mismatched_lengths, "fl[{1, 2}, {3}]", "fl[{1, 2}, {3}]");
+        attributes_test!(no_lists, "fl[1, 2]", "fl[1, 2]");
+        attributes_test!(non_listable, "f[{1, 2}, 10]", "f[{1, 2}, 10]");
+    }
+
+    mod one_identity {
+        use super::*;
+
+        attributes_test!(collapses, "fo[x]", "x");
+        attributes_test!(collapses_nested, "fo[fo[x]]", "x");
+        attributes_test!(multiple_arguments, "fo[x, y]", "fo[x, y]");
+        attributes_test!(no_arguments, "fo[]", "fo[]");
+        attributes_test!(after_splicing, "fo[Sequence[x]]", "x");
+        attributes_test!(spliced_arguments, "fo[Sequence[x, y]]", "fo[x, y]");
+        attributes_test!(plus, "Plus[x]", "x");
+        attributes_test!(plus_multiple_arguments, "Plus[x, y]", "Plus[x, y]");
+        attributes_test!(times, "Times[x]", "x");
+    }
+
+    mod conditions {
+        evaluate_test!(satisfied, ["f[x_] := x /; x > 0"], "f[5]", "5");
+        evaluate_test!(unsatisfied, ["f[x_] := x /; x > 0"], "f[-5]", "f[-5]");
+        evaluate_test!(undecided, ["f[x_] := x /; x > 0"], "f[y]", "f[y]");
+        evaluate_test!(
+            falls_through,
+            ["f[x_] := positive /; x > 0", "f[x_] := other"],
+            "{f[5], f[-5]}",
+            "{positive, other}"
+        );
+        evaluate_test!(
+            nested,
+            ["f[x_] := positive /; x > 0"],
+            "g[f[5]]",
+            "g[positive]"
+        );
+        evaluate_test!(
+            backtracks,
+            ["f[x___, y_, z___] := y /; y > 2"],
+            "f[1, 2, 3, 4]",
+            "3"
+        );
+        evaluate_test!(inline_satisfied, ["f[x_ /; x > 0] := x"], "f[5]", "5");
+        evaluate_test!(
+            inline_unsatisfied,
+            ["f[x_ /; x > 0] := x"],
+            "f[-5]",
+            "f[-5]"
+        );
+        evaluate_test!(
+            inline_refers_to_other_bindings,
+            ["f[x_, y_ /; y < x] := {x, y}"],
+            "{f[3, 1], f[3, 4]}",
+            "{{3, 1}, f[3, 4]}"
+        );
+        evaluate_test!(
+            inline_backtracks,
+            ["f[x___, y_ /; y > 2, z___] := y"],
+            "f[1, 2, 3, 4]",
+            "3"
+        );
+    }
+
+    mod exceptions {
+        evaluate_test!(
+            mapped_over_list,
+            ["f[Except[0]] := nonzero"],
+            "f /@ {1, 0, 2}",
+            "{nonzero, f[0], nonzero}"
+        );
+        evaluate_test!(
+            with_pattern,
+            ["f[Except[0, x_Integer]] := x"],
+            "f /@ {1, 0, a}",
+            "{1, f[0], f[a]}"
+        );
+    }
+
+    mod pattern_tests {
+        evaluate_test!(
+            satisfied,
+            ["positive[n_] := n > 0", "f[x_?positive] := x"],
+            "f[5]",
+            "5"
+        );
+        evaluate_test!(
+            unsatisfied,
+            ["positive[n_] := n > 0", "f[x_?positive] := x"],
+            "f[-5]",
+            "f[-5]"
+        );
+        evaluate_test!(
+            undecided,
+            ["positive[n_] := n > 0", "f[x_?positive] := x"],
+            "f[y]",
+            "f[y]"
+        );
+        evaluate_test!(
+            with_head_constraint,
+            ["positive[n_] := n > 0", "f[x_Integer?positive] := x"],
+            "{f[5], f[5.0]}",
+            "{5, f[5.0]}"
+        );
+        evaluate_test!(
+            backtracks,
+            ["positive[n_] := n > 2", "f[x___, y_?positive, z___] := y"],
+            "f[1, 2, 3, 4]",
+            "3"
+        );
+    }
+
+    mod own_values {
+        use super::*;
+
+        evaluate_test!(assigned, ["x = 5"], "x", "5");
+        evaluate_test!(reassigned, ["x = 5", "x = 6"], "x", "6");
+        evaluate_test!(delayed, ["x := 1 + 2"], "x", "3");
+        evaluate_test!(chained, ["x := y", "y = 2"], "x", "2");
+        evaluate_test!(in_expression, ["x = 5"], "f[x, x + 1]", "f[5, 6]");
+        evaluate_test!(unassigned, ["x = 5"], "y", "y");
+
+        #[test]
+        fn stored_as_own_values() {
+            let mut context = create_context();
+
+            evaluate(parse_str("x = 5").unwrap(), &mut context).unwrap();
+            evaluate(parse_str("x = 6").unwrap(), &mut context).unwrap();
+
+            let x = Symbol::new("x");
+            let values = context.get_values(&x, ValueType::OwnValue).unwrap();
+
+            assert_eq!(values.len(), 1);
+            assert_eq!(values[0].pattern(), &parse_str("x").unwrap());
+            assert!(
+                context
+                    .get_values(&x, ValueType::DownValue)
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+    }
+
+    mod up_values {
+        use super::*;
+
+        fn set_up_value(context: &mut Context, symbol: &str, pattern: &str, ground: &str) {
+            context
+                .set_value(
+                    &Symbol::new(symbol),
+                    ValueType::UpValue,
+                    SymbolValue::Definitions {
+                        pattern: parse_str(pattern).unwrap(),
+                        condition: None,
+                        ground: parse_str(ground).unwrap(),
+                    },
+                )
+                .unwrap();
+        }
+
+        fn evaluate_with_up_value(input: &str) -> Expr {
+            let mut context = create_context();
+            set_up_value(&mut context, "g", "f[g[x_]]", "x + 1");
+            set_up_value(&mut context, "g", "area[g]", "42");
+            set_up_value(&mut context, "g", "fhc[g[x_]]", "x");
+
+            evaluate(parse_str("f[x_] := down").unwrap(), &mut context).unwrap();
+
+            evaluate(parse_str(input).unwrap(), &mut context).unwrap()
+        }
+
+        #[test]
+        fn rewrites_element_head() {
+            assert_eq!(evaluate_with_up_value("f[g[1]]"), parse_str("2").unwrap());
+        }
+
+        #[test]
+        fn rewrites_symbol_element() {
+            assert_eq!(evaluate_with_up_value("area[g]"), parse_str("42").unwrap());
+        }
+
+        #[test]
+        fn applied_before_down_values() {
+            assert_eq!(
+                evaluate_with_up_value("f[h[1]]"),
+                parse_str("down").unwrap()
+            );
+            assert_eq!(evaluate_with_up_value("f[g[1]]"), parse_str("2").unwrap());
+        }
+
+        #[test]
+        fn unmatched() {
+            assert_eq!(
+                evaluate_with_up_value("k[g[1]]"),
+                parse_str("k[g[1]]").unwrap()
+            );
+        }
+
+        #[test]
+        fn not_applied_within_hold_all_complete() {
+            assert_eq!(
+                evaluate_with_up_value("fhc[g[1]]"),
+                parse_str("fhc[g[1]]").unwrap()
+            );
+        }
+    }
+
+    mod sub_values {
+        use super::*;
+
+        evaluate_test!(applied, ["f[n_][x_] := n + x"], "f[2][3]", "5");
+        evaluate_test!(
+            unmatched,
+            ["f[n_][x_] := n + x"],
+            "f[2][3, 4]",
+            "f[2][3, 4]"
+        );
+        evaluate_test!(
+            nested,
+            ["f[n_][x_][y_] := {n, x, y}"],
+            "f[1][2][3]",
+            "{1, 2, 3}"
+        );
+        evaluate_test!(
+            head_evaluated_first,
+            ["f[n_] := g[n + 1]", "g[n_][x_] := n * x"],
+            "f[1][3]",
+            "6"
+        );
+        evaluate_test!(
+            not_applied_to_down_values,
+            ["f[n_][x_] := n + x"],
+            "f[2]",
+            "f[2]"
+        );
+
+        #[test]
+        fn stored_as_sub_values() {
+            let mut context = create_context();
+
+            evaluate(parse_str("f[n_][x_] := n + x").unwrap(), &mut context).unwrap();
+
+            let f = Symbol::new("f");
+            assert!(
+                context
+                    .get_values(&f, ValueType::DownValue)
+                    .unwrap()
+                    .is_empty()
+            );
+            assert_eq!(
+                context.get_values(&f, ValueType::SubValue).unwrap().len(),
+                1
+            );
+        }
+    }
+
+    mod specificity {
+        use super::*;
+
+        evaluate_test!(
+            specific_defined_last,
+            ["f[x_] = b", "f[0] = a"],
+            "{f[0], f[1]}",
+            "{a, b}"
+        );
+        evaluate_test!(
+            specific_defined_first,
+            ["f[0] = a", "f[x_] = b"],
+            "{f[0], f[1]}",
+            "{a, b}"
+        );
+        evaluate_test!(
+            head_constraint,
+            ["f[x_] := general", "f[x_Integer] := integer"],
+            "{f[1], f[y]}",
+            "{integer, general}"
+        );
+        evaluate_test!(
+            sequences_last,
+            ["f[x__] := many", "f[x_] := one"],
+            "{f[1], f[1, 2]}",
+            "{one, many}"
+        );
+        evaluate_test!(redefined, ["f[x_] := 1", "f[x_] := 2"], "f[0]", "2");
+        evaluate_test!(
+            redefined_with_condition,
+            ["f[x_] := 1 /; x > 0", "f[x_] := 2", "f[x_] := 3 /; x > 0"],
+            "{f[1], f[-1]}",
+            "{3, 2}"
+        );
+        evaluate_test!(
+            defined_order_kept,
+            ["f[x_] := x /; x > 0", "f[x_] := other"],
+            "{f[1], f[-1]}",
+            "{1, other}"
+        );
+
+        #[test]
+        fn stored_in_order() {
+            let mut context = create_context();
+
+            evaluate(parse_str("f[x__] := 3").unwrap(), &mut context).unwrap();
+            evaluate(parse_str("f[x_] := 2").unwrap(), &mut context).unwrap();
+            evaluate(parse_str("f[0] := 1").unwrap(), &mut context).unwrap();
+
+            let patterns = context
+                .get_values(&Symbol::new("f"), ValueType::DownValue)
+                .unwrap()
+                .iter()
+                .map(|value| value.pattern().clone())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                patterns,
+                vec![
+                    parse_str("f[0]").unwrap(),
+                    parse_str("f[x_]").unwrap(),
+                    parse_str("f[x__]").unwrap(),
+                ]
+            );
+        }
+    }
+
+    mod limits {
+        use super::*;
+
+        #[test]
+        fn iteration_limit() {
+            let mut context = create_context();
+            context.set_iteration_limit(100);
+
+            evaluate(parse_str("f[x_] := f[x]").unwrap(), &mut context).unwrap();
+
+            assert_eq!(
+                evaluate(parse_str("f[1]").unwrap(), &mut context).unwrap(),
+                parse_str("Hold[f[1]]").unwrap()
+            );
+        }
+
+        #[test]
+        fn iteration_limit_growing_expression() {
+            let mut context = create_context();
+            context.set_iteration_limit(100);
+
+            evaluate(parse_str("f[x_] := f[x + 1]").unwrap(), &mut context).unwrap();
+
+            let result = evaluate(parse_str("f[0]").unwrap(), &mut context).unwrap();
+            assert!(result.is_normal_head(&sym!(Hold)));
+        }
+
+        #[test]
+        fn recursion_limit() {
+            let mut context = create_context();
+
+            // Checking the condition evaluates `f[x]` again, which checks the condition again.
+            evaluate(parse_str("f[x_] := 1 /; f[x] > 0").unwrap(), &mut context).unwrap();
+
+            assert_eq!(
+                evaluate(parse_str("f[1]").unwrap(), &mut context).unwrap(),
+                parse_str("f[1]").unwrap()
+            );
+            assert_eq!(context.evaluation_depth(), 0);
+        }
+
+        #[test]
+        fn recursion_limit_returns_held() {
+            let mut context = create_context();
+            context.set_recursion_limit(0);
+
+            assert_eq!(
+                evaluate(parse_str("1 + 1").unwrap(), &mut context).unwrap(),
+                parse_str("Hold[1 + 1]").unwrap()
+            );
+        }
+
+        evaluate_test!(
+            within_limits,
+            ["f[0] := 0", "f[n_] := 1 + f[n - 1]"],
+            "f[20]",
+            "20"
+        );
+    }
+
+    mod nesting {
+        use super::*;
+
+        const DEPTH: usize = 100_000;
+
+        /// Returns `f[f[...f[x]...]]`, nested to the given depth.
+        fn nested(depth: usize) -> Expr {
+            let mut expr = Expr::from(Symbol::new("x"));
+
+            for _ in 0..depth {
+                expr = Expr::from(Normal::new(Symbol::new("f"), vec![expr]));
+            }
+
+            expr
+        }
+
+        /// Returns the innermost element of `f[f[...f[x]...]]`, along with its depth.
+        fn innermost(mut expr: &Expr) -> (&Expr, usize) {
+            let mut depth = 0;
+
+            while let Some(normal) = expr.try_normal() {
+                expr = normal.element(0).unwrap();
+                depth += 1;
+            }
+
+            (expr, depth)
+        }
+
+        #[test]
+        fn evaluate_unchanged() {
+            let mut context = create_context();
+            let expr = nested(DEPTH);
+
+            assert_eq!(evaluate(expr.clone(), &mut context).unwrap(), expr);
+        }
+
+        #[test]
+        fn evaluate_innermost() {
+            let mut context = create_context();
+            evaluate(parse_str("x = 1").unwrap(), &mut context).unwrap();
+
+            let result = evaluate(nested(DEPTH), &mut context).unwrap();
+
+            assert_eq!(innermost(&result), (&parse_str("1").unwrap(), DEPTH));
+        }
+
+        #[test]
+        fn replace_all_innermost() {
+            let bindings = SolutionSet::from([(Symbol::new("x"), parse_str("1").unwrap())]);
+
+            let result = replace_all(&bindings, nested(DEPTH));
+
+            assert!(result.is_changed());
+            assert_eq!(innermost(result.expr()), (&parse_str("1").unwrap(), DEPTH));
+        }
+    }
+}
@@ -228,6 +228,12 @@ pub fn parse_any_sequence_variable(expr: &Expr) -> Option<(bool, Option<&Symbol>
     None
 }
 
+/// Checks that `expr` satisfies the head constraint `h` of a blank, if one is present. For example
+/// `x_Integer` only matches expressions with head `Integer`.
+pub fn matches_head_constraint(expr: &Expr, h: Option<&Expr>) -> bool {
+    h.is_none_or(|h| expr.head() == *h)
+}
+
 pub fn extract_condition(expr: &Expr) -> (&Expr, Option<&Expr>) {
     if let Some(normal) = expr.try_normal_head(&sym!(Condition)) {
         if normal.len() == 2 {
@@ -237,3 +243,16 @@ pub fn extract_condition(expr: &Expr) -> (&Expr, Option<&Expr>) {
 
     (expr, None)
 }
+
+/// Removes any `HoldPattern[...]` wrappers from `expr`, returning the pattern being held.
+pub fn strip_hold_pattern(expr: &Expr) -> &Expr {
+    let mut expr = expr;
+
+    while let Some(normal) = expr.try_normal_head(&sym!(HoldPattern))
+        && normal.len() == 1
+    {
+        expr = &normal.elements()[0];
+    }
+
+    expr
+}
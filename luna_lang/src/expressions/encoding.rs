@@ -0,0 +1,372 @@
+use crate::{BigFloat, BigInteger, BigRational, Expr, ExprKind, Normal, OrdBigFloat, Symbol};
+use rug::integer::Order;
+
+const STRING_TAG: u8 = 0;
+const INTEGER_TAG: u8 = 1;
+const RATIONAL_TAG: u8 = 2;
+const REAL_TAG: u8 = 3;
+const SYMBOL_TAG: u8 = 4;
+const NORMAL_TAG: u8 = 5;
+
+impl Expr {
+    /// Encodes the expression into a compact binary form, suitable for caching. Each expression is
+    /// a tag byte followed by its contents, with strings and children prefixed by their length.
+    /// Integers, rationals and reals are encoded losslessly. Fails if a string or list of elements
+    /// is too long for its length to be encoded.
+    pub fn encode(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = Vec::new();
+        encode_expr(self, &mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Decodes an expression previously encoded by [`Expr::encode`].
+    pub fn decode(bytes: &[u8]) -> Result<Expr, String> {
+        let mut decoder = Decoder { bytes, position: 0 };
+        let expr = decoder.expr()?;
+
+        if decoder.position != bytes.len() {
+            return Err(format!(
+                "Unexpected trailing bytes at position {}",
+                decoder.position
+            ));
+        }
+
+        Ok(expr)
+    }
+}
+
+/// The parts of an expression still to be encoded, see [`encode_expr`].
+enum Pending<'a> {
+    Expr(&'a Expr),
+    Length(usize),
+}
+
+fn encode_expr(expr: &Expr, bytes: &mut Vec<u8>) -> Result<(), String> {
+    // Rather than recursing, the parts still to be encoded are kept on an explicit stack so that
+    // deeply nested expressions do not overflow the native stack.
+    let mut pending = vec![Pending::Expr(expr)];
+
+    while let Some(next) = pending.pop() {
+        let expr = match next {
+            Pending::Expr(expr) => expr,
+            Pending::Length(length) => {
+                encode_length(length, bytes)?;
+                continue;
+            }
+        };
+
+        match expr.kind() {
+            ExprKind::String(value) => {
+                bytes.push(STRING_TAG);
+                encode_str(value, bytes)?;
+            }
+            ExprKind::Integer(value) => {
+                bytes.push(INTEGER_TAG);
+                encode_integer(value, bytes)?;
+            }
+            ExprKind::Rational(value) => {
+                bytes.push(RATIONAL_TAG);
+                encode_integer(value.numer(), bytes)?;
+                encode_integer(value.denom(), bytes)?;
+            }
+            ExprKind::Real(value) => {
+                // With no digit count, the digits produced read back exactly at the same precision.
+                bytes.push(REAL_TAG);
+                encode_length(value.as_float().prec() as usize, bytes)?;
+                encode_str(&value.as_float().to_string_radix(16, None), bytes)?;
+            }
+            ExprKind::Symbol(value) => {
+                bytes.push(SYMBOL_TAG);
+                encode_str(value.as_str(), bytes)?;
+            }
+            ExprKind::Normal(value) => {
+                // The head is encoded first, followed by the number of elements and the elements.
+                bytes.push(NORMAL_TAG);
+                pending.extend(value.elements().iter().rev().map(Pending::Expr));
+                pending.push(Pending::Length(value.len()));
+                pending.push(Pending::Expr(value.head()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_length(length: usize, bytes: &mut Vec<u8>) -> Result<(), String> {
+    let length =
+        u32::try_from(length).map_err(|_| format!("Length {} is too long to encode", length))?;
+    bytes.extend_from_slice(&length.to_le_bytes());
+
+    Ok(())
+}
+
+fn encode_str(value: &str, bytes: &mut Vec<u8>) -> Result<(), String> {
+    encode_length(value.len(), bytes)?;
+    bytes.extend_from_slice(value.as_bytes());
+
+    Ok(())
+}
+
+fn encode_integer(value: &BigInteger, bytes: &mut Vec<u8>) -> Result<(), String> {
+    bytes.push(u8::from(value.is_negative()));
+
+    let digits = value.to_digits::<u8>(Order::Lsf);
+    encode_length(digits.len(), bytes)?;
+    bytes.extend_from_slice(&digits);
+
+    Ok(())
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+/// A normal expression part way through being decoded, see [`Decoder::expr`].
+struct NormalFrame {
+    head: Option<Expr>,
+    length: usize,
+    elements: Vec<Expr>,
+}
+
+impl<'a> Decoder<'a> {
+    fn expr(&mut self) -> Result<Expr, String> {
+        // As with encoding, normal expressions are decoded using an explicit stack of those still
+        // missing their head or some of their elements, rather than by recursing.
+        let mut frames: Vec<NormalFrame> = vec![];
+
+        loop {
+            let mut result = match self.atom()? {
+                Some(atom) => atom,
+                None => {
+                    frames.push(NormalFrame {
+                        head: None,
+                        length: 0,
+                        elements: vec![],
+                    });
+                    continue;
+                }
+            };
+
+            loop {
+                let Some(frame) = frames.last_mut() else {
+                    return Ok(result);
+                };
+
+                if frame.head.is_none() {
+                    frame.head = Some(result);
+                    frame.length = self.length()?;
+
+                    // Each element takes at least one byte, which bounds the allocation.
+                    frame.elements = Vec::with_capacity(frame.length.min(self.bytes.len()));
+                } else {
+                    frame.elements.push(result);
+                }
+
+                if frame.elements.len() < frame.length {
+                    break;
+                }
+
+                let frame = frames.pop().unwrap();
+                result = Expr::from(Normal::new(frame.head.unwrap(), frame.elements));
+            }
+        }
+    }
+
+    /// Decodes an expression other than a normal expression. Returns `None` after taking the tag
+    /// of a normal expression, leaving its head and elements to be decoded.
+    fn atom(&mut self) -> Result<Option<Expr>, String> {
+        let tag_position = self.position;
+
+        let atom = match self.take(1)?[0] {
+            STRING_TAG => Expr::from(self.str()?.to_owned()),
+            INTEGER_TAG => Expr::from(self.integer()?),
+            RATIONAL_TAG => {
+                let numerator = self.integer()?;
+                let denominator = self.integer()?;
+
+                if denominator.is_zero() {
+                    return Err("Rational with a zero denominator".to_owned());
+                }
+
+                Expr::from(BigRational::from((numerator, denominator)))
+            }
+            REAL_TAG => {
+                let precision = self.length()? as u32;
+                let digits = self.str()?;
+                let value = BigFloat::parse_radix(digits, 16).map_err(|error| error.to_string())?;
+
+                if !(rug::float::prec_min()..=rug::float::prec_max()).contains(&precision) {
+                    return Err(format!("Invalid real precision {}", precision));
+                }
+
+                Expr::from(OrdBigFloat::from(BigFloat::with_val(precision, value)))
+            }
+            SYMBOL_TAG => Expr::from(Symbol::new(self.str()?)),
+            NORMAL_TAG => return Ok(None),
+            tag => return Err(format!("Unknown tag {} at position {}", tag, tag_position)),
+        };
+
+        Ok(Some(atom))
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| format!("Unexpected end of input at position {}", self.position))?;
+
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    fn length(&mut self) -> Result<usize, String> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+    }
+
+    fn str(&mut self) -> Result<&'a str, String> {
+        let length = self.length()?;
+        std::str::from_utf8(self.take(length)?).map_err(|error| error.to_string())
+    }
+
+    fn integer(&mut self) -> Result<BigInteger, String> {
+        let negative = self.take(1)?[0] != 0;
+        let length = self.length()?;
+        let value = BigInteger::from_digits(self.take(length)?, Order::Lsf);
+
+        Ok(if negative { -value } else { value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigFloat, BigInteger, BigRational, Expr, Normal, Symbol, parse_str};
+
+    /// A small xorshift generator, so that the generated expressions are reproducible.
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: u64) -> u64 {
+            self.next() % bound
+        }
+    }
+
+    fn random_expr(random: &mut Random, depth: u32) -> Expr {
+        match random.below(if depth == 0 { 5 } else { 7 }) {
+            0 => Expr::from(format!("s{}\n\u{e9}", random.below(100))),
+            1 => {
+                let value = BigInteger::from(random.next()) * BigInteger::from(random.next());
+
+                if random.below(2) == 0 {
+                    Expr::from(value)
+                } else {
+                    Expr::from(-value)
+                }
+            }
+            2 => Expr::from(BigRational::from((
+                random.next() as i64,
+                random.below(1000) + 1,
+            ))),
+            3 => {
+                let precision = 2 + random.below(300) as u32;
+                let value = BigFloat::with_val(precision, random.next() as i64) / 7;
+
+                Expr::from(value)
+            }
+            4 => Expr::from(Symbol::new(&format!("x{}", random.below(10)))),
+            _ => {
+                let head = random_expr(random, depth - 1);
+                let elements = (0..random.below(4))
+                    .map(|_| random_expr(random, depth - 1))
+                    .collect::<Vec<_>>();
+
+                Expr::from(Normal::new(head, elements))
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_parsed() {
+        let expr = parse_str("f[x_, {1, -2.5, \"a\"}, 3/4, 10^40] := g[x] + 1").unwrap();
+
+        assert_eq!(Expr::decode(&expr.encode().unwrap()).unwrap(), expr);
+    }
+
+    #[test]
+    fn round_trip_random() {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..500 {
+            let expr = random_expr(&mut random, 4);
+
+            assert_eq!(Expr::decode(&expr.encode().unwrap()).unwrap(), expr);
+        }
+    }
+
+    #[test]
+    fn real_precision_is_preserved() {
+        let expr = Expr::from(BigFloat::with_val(200, 1) / 3);
+        let decoded = Expr::decode(&expr.encode().unwrap()).unwrap();
+
+        assert_eq!(decoded.try_real().unwrap().as_float().prec(), 200);
+        assert_eq!(decoded, expr);
+    }
+
+    #[test]
+    fn truncated_input() {
+        let bytes = parse_str("f[x, y]").unwrap().encode().unwrap();
+
+        for length in 0..bytes.len() {
+            assert!(Expr::decode(&bytes[..length]).is_err());
+        }
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        let mut bytes = parse_str("x").unwrap().encode().unwrap();
+        bytes.push(0);
+
+        assert!(Expr::decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn unknown_tag() {
+        assert!(Expr::decode(&[42]).is_err());
+    }
+
+    #[test]
+    fn deeply_nested() {
+        let mut expr = Expr::from(Symbol::new("x"));
+        for _ in 0..100_000 {
+            expr = Expr::from(Normal::new(Symbol::new("f"), vec![expr]));
+        }
+
+        // Comparing the expressions themselves would recurse, so their encodings are compared.
+        let bytes = expr.encode().unwrap();
+        let decoded = Expr::decode(&bytes).unwrap();
+
+        assert_eq!(decoded.encode().unwrap(), bytes);
+    }
+
+    #[test]
+    fn crafted_nesting() {
+        // Normal expressions whose heads are themselves normal expressions, without end.
+        assert!(Expr::decode(&[5; 200_000]).is_err());
+
+        let mut bytes = vec![5; 200_000];
+        bytes.extend(Expr::from(Symbol::new("f")).encode().unwrap());
+        assert!(Expr::decode(&bytes).is_err());
+    }
+}
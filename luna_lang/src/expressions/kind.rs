@@ -1,14 +1,28 @@
 use crate::Symbol;
 use crate::expressions::normal::Normal;
-use crate::{BigFloat, BigInteger, OrdBigFloat};
+use crate::{BigFloat, BigInteger, BigRational, OrdBigFloat};
 use std::fmt;
 use std::fmt::Formatter;
 use std::hash::{Hash, Hasher};
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExprKind {
     String(String),
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::abstractions::serde_numbers::integer")
+    )]
     Integer(BigInteger),
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::abstractions::serde_numbers::rational")
+    )]
+    Rational(BigRational),
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::abstractions::serde_numbers::real")
+    )]
     Real(OrdBigFloat),
     Symbol(Symbol),
     Normal(Normal),
@@ -32,6 +46,12 @@ impl From<BigInteger> for ExprKind {
     }
 }
 
+impl From<BigRational> for ExprKind {
+    fn from(value: BigRational) -> Self {
+        Self::Rational(value)
+    }
+}
+
 impl From<BigFloat> for ExprKind {
     fn from(value: BigFloat) -> Self {
         Self::Real(OrdBigFloat::from(value))
@@ -67,6 +87,7 @@ impl Hash for ExprKind {
 
         const STRING_PREFIX: [u8; 8] = [102, 206, 57, 172, 207, 100, 198, 133];
         const INTEGER_PREFIX: [u8; 8] = [242, 99, 84, 113, 102, 46, 118, 94];
+        const RATIONAL_PREFIX: [u8; 8] = [29, 173, 130, 12, 91, 240, 61, 187];
         const REAL_PREFIX: [u8; 8] = [195, 244, 76, 249, 227, 115, 88, 251];
         const SYMBOL_PREFIX: [u8; 8] = [107, 10, 247, 23, 33, 221, 163, 156];
         const EXPR_PREFIX: [u8; 8] = [72, 5, 244, 86, 5, 210, 69, 30];
@@ -82,6 +103,11 @@ impl Hash for ExprKind {
                 v.hash(hasher);
             }
 
+            Self::Rational(v) => {
+                hasher.write(&RATIONAL_PREFIX);
+                v.hash(hasher);
+            }
+
             Self::Real(v) => {
                 hasher.write(&REAL_PREFIX);
                 v.hash(hasher);
@@ -109,11 +135,32 @@ impl fmt::Debug for ExprKind {
 impl fmt::Display for ExprKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Self::String(v) => write!(f, "\"{}\"", v),
+            Self::String(v) => write_escaped_string(f, v),
             Self::Integer(v) => write!(f, "{}", v),
+            Self::Rational(v) => write!(f, "{}", v),
             Self::Real(v) => write!(f, "{}", v.as_float()),
             Self::Symbol(v) => write!(f, "{}", v),
             Self::Normal(v) => write!(f, "{}", v),
         }
     }
 }
+
+/// Writes a string in quotes, escaping the characters which cannot appear verbatim so that the
+/// output parses back to the same string.
+fn write_escaped_string(f: &mut Formatter<'_>, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for c in value.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\t' => write!(f, "\\t")?,
+            '\r' => write!(f, "\\r")?,
+            c if c.is_control() => write!(f, "\\:{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+
+    write!(f, "\"")
+}
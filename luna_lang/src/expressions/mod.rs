@@ -1,8 +1,9 @@
+mod encoding;
 mod kind;
 mod normal;
 mod symbol;
 
-use crate::abstractions::{BigFloat, BigInteger};
+use crate::abstractions::{BigFloat, BigInteger, BigRational};
 use crate::{OrdBigFloat, sym};
 use std::fmt;
 use std::fmt::Formatter;
@@ -14,7 +15,7 @@ pub use normal::Normal;
 pub use symbol::Symbol;
 
 /// Representation of an expression node. An expression can be either an "atomic" value (such as
-/// a string, integer, real, or symbol) or a "normal" expression which is of the form
+/// a string, integer, rational, real, or symbol) or a "normal" expression which is of the form
 /// `f[a1, ..., an]` where `f` is the "head" and `a1, ..., an` represents zero or more `elements`
 /// of the expression.
 ///
@@ -44,6 +45,7 @@ impl Expr {
         match *self.0 {
             ExprKind::String(_) => Self::from(sym!(String)),
             ExprKind::Integer(_) => Self::from(sym!(Integer)),
+            ExprKind::Rational(_) => Self::from(sym!(Rational)),
             ExprKind::Real(_) => Self::from(sym!(Real)),
             ExprKind::Symbol(_) => Self::from(sym!(Symbol)),
             ExprKind::Normal(ref v) => v.head().clone(),
@@ -71,6 +73,13 @@ impl Expr {
         }
     }
 
+    pub fn try_rational(&self) -> Option<&BigRational> {
+        match *self.0 {
+            ExprKind::Rational(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
     pub fn try_real(&self) -> Option<&OrdBigFloat> {
         match *self.0 {
             ExprKind::Real(ref v) => Some(v),
@@ -118,6 +127,12 @@ impl From<BigInteger> for Expr {
     }
 }
 
+impl From<BigRational> for Expr {
+    fn from(value: BigRational) -> Self {
+        Self::new(ExprKind::from(value))
+    }
+}
+
 impl From<BigFloat> for Expr {
     fn from(value: BigFloat) -> Self {
         Self::new(ExprKind::from(value))
@@ -142,6 +157,46 @@ impl From<Normal> for Expr {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Expr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.kind().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Expr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ExprKind::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl Drop for Expr {
+    fn drop(&mut self) {
+        // Dropping a deeply nested expression would otherwise recurse once for each level of
+        // nesting, so the elements of uniquely owned normal expressions are moved onto a stack and
+        // dropped from there instead.
+        let mut stack = vec![];
+        detach_elements(self, &mut stack);
+
+        while let Some(mut expr) = stack.pop() {
+            detach_elements(&mut expr, &mut stack);
+        }
+    }
+}
+
+/// Moves the normal elements of `expr` onto the `stack`, if `expr` is about to be freed.
+fn detach_elements(expr: &mut Expr, stack: &mut Vec<Expr>) {
+    if let Some(ExprKind::Normal(normal)) = Arc::get_mut(&mut expr.0) {
+        let elements = normal.take_elements().into_vec();
+        stack.extend(
+            elements
+                .into_iter()
+                .filter(|elem| elem.try_normal().is_some()),
+        );
+    }
+}
+
 impl fmt::Debug for Expr {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self, f)
@@ -153,3 +208,54 @@ impl fmt::Display for Expr {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    mod serde {
+        use crate::{BigFloat, BigInteger, BigRational, Expr, Normal, Symbol, real_precision};
+
+        fn round_trip(expr: &Expr) -> Expr {
+            let json = serde_json::to_string(expr).unwrap();
+            serde_json::from_str(&json).unwrap()
+        }
+
+        #[test]
+        fn mixed_expression() {
+            let expr = Expr::from(Normal::new(
+                Symbol::new("f"),
+                vec![
+                    Expr::from(BigInteger::from(BigInteger::u_pow_u(10, 40))),
+                    Expr::from(BigFloat::with_val(200, 1.25)),
+                    Expr::from(BigRational::from((-3, 7))),
+                    Expr::from("a \"string\""),
+                    Expr::from(Normal::new(
+                        Expr::from(Symbol::new("g")),
+                        vec![Expr::from(Symbol::new("x"))],
+                    )),
+                ],
+            ));
+
+            assert_eq!(round_trip(&expr), expr);
+        }
+
+        #[test]
+        fn integers_are_strings() {
+            let expr = Expr::from(BigInteger::from(BigInteger::u_pow_u(10, 40)));
+
+            assert_eq!(
+                serde_json::to_string(&expr).unwrap(),
+                "{\"Integer\":\"10000000000000000000000000000000000000000\"}"
+            );
+        }
+
+        #[test]
+        fn real_precision_is_preserved() {
+            let value = BigFloat::with_val(200, 1) / 3;
+            let expr = Expr::from(value);
+            let result = round_trip(&expr);
+
+            assert_eq!(result, expr);
+            assert_eq!(real_precision(result.try_real().unwrap()), 200);
+        }
+    }
+}
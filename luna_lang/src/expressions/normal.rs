@@ -6,6 +6,7 @@ use std::hash::Hash;
 
 /// Represents a normal expression of the form `f[...]`.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Normal {
     head: Expr,
     elements: Box<[Expr]>,
@@ -27,6 +28,11 @@ impl Normal {
         &self.elements
     }
 
+    /// Takes the elements, leaving none behind.
+    pub(crate) fn take_elements(&mut self) -> Box<[Expr]> {
+        std::mem::take(&mut self.elements)
+    }
+
     pub fn element(&self, idx: usize) -> Option<&Expr> {
         self.elements.get(idx)
     }
@@ -2,6 +2,9 @@ use crate::IString;
 use std::fmt;
 use std::fmt::Formatter;
 
+/// A symbol, such as `x` or `Plus`. Symbol names are interned, so creating a symbol only allocates
+/// the first time a name is seen, and testing symbols for equality or hashing them never compares
+/// the names themselves. Ordering symbols does compare their names, lexicographically.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Symbol(IString);
 
@@ -9,6 +12,10 @@ impl Symbol {
     pub fn new(value: &str) -> Self {
         Self(IString::from(value))
     }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
 }
 
 impl fmt::Debug for Symbol {
@@ -22,3 +29,48 @@ impl fmt::Display for Symbol {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Symbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Symbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Ok(Symbol::new(&name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Symbol;
+    use std::hash::{BuildHasher, RandomState};
+
+    #[test]
+    fn interned() {
+        let a = Symbol::new("f");
+        let b = Symbol::new(&String::from("f"));
+
+        assert_eq!(a, b);
+        assert_eq!(a.as_str().as_ptr(), b.as_str().as_ptr());
+    }
+
+    #[test]
+    fn distinct_names() {
+        assert_ne!(Symbol::new("f"), Symbol::new("g"));
+    }
+
+    #[test]
+    fn stable_hash() {
+        let state = RandomState::new();
+
+        assert_eq!(
+            state.hash_one(Symbol::new("f")),
+            state.hash_one(Symbol::new("f"))
+        );
+    }
+}
@@ -1,5 +1,9 @@
 // https://github.com/rljacobson/lorislib/blob/f2ffa634d14eb74f898ae07225802312fece3b99/src/format.rs
 
+#[cfg(test)]
+#[macro_use]
+mod testing;
+
 mod abstractions;
 mod attributes;
 mod builtins;
@@ -1,12 +1,16 @@
 use crate::Symbol;
 use crate::matching::MatchRule;
+use crate::matching::rule_ce::RuleCE;
 use crate::matching::rule_dc::RuleDC;
+use crate::matching::rule_dh::RuleDH;
 use crate::matching::rule_dnc::RuleDNC;
+use crate::matching::rule_except::RuleExcept;
 use crate::matching::rule_fve::RuleFVE;
 use crate::matching::rule_fvea::RuleFVEA;
 use crate::matching::rule_fveac::RuleFVEAC;
 use crate::matching::rule_ivea::RuleIVEA;
 use crate::matching::rule_iveac::RuleIVEAC;
+use crate::matching::rule_pt::RulePT;
 use crate::matching::rule_svea::RuleSVEA;
 use crate::matching::rule_sveac::RuleSVEAC;
 use crate::matching::rule_svec::RuleSVEC;
@@ -14,8 +18,8 @@ use crate::matching::rule_svef::RuleSVEF;
 use crate::matching::rule_t::RuleT;
 use crate::matching::rule_ve::RuleVE;
 use crate::{
-    Context, Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, SolutionSet,
-    Substitution,
+    Context, Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, Normal,
+    SolutionSet, Substitution, check_condition, strip_hold_pattern,
 };
 use std::collections::HashMap;
 
@@ -34,11 +38,17 @@ enum MatchStack {
 
     /// An operation representing pushing matching equations onto the equation stack.
     ProducedMatchEquations(usize),
+
+    /// An operation representing pushing a condition onto the condition stack.
+    ProducedCondition,
 }
 
 /// Holds the state of the in-process pattern matching attempt.
 pub struct Matcher<'c> {
-    context: &'c Context,
+    context: &'c mut Context,
+
+    /// An optional condition which the bindings of a successful match must satisfy.
+    condition: Option<Expr>,
 
     /// The match_stack is where operations that change the state are recorded.
     /// Operations are pushed when they are done and popped when they are undone.
@@ -49,31 +59,117 @@ pub struct Matcher<'c> {
 
     /// The symbol table recording all variable/sequence variable bindings.
     substitutions: SolutionSet,
+
+    /// Conditions from within the pattern which the bindings of a successful match must satisfy.
+    condition_stack: Vec<Expr>,
 }
 
 impl<'c> Matcher<'c> {
-    pub fn new(pattern: Expr, ground: Expr, context: &'c Context) -> Self {
+    pub fn new(pattern: Expr, ground: Expr, context: &'c mut Context) -> Self {
         Self {
             context,
+            condition: None,
             match_stack: Vec::new(),
             equation_stack: vec![MatchEquation { pattern, ground }],
             substitutions: HashMap::new(),
+            condition_stack: Vec::new(),
+        }
+    }
+
+    /// Restricts matches to those whose bindings satisfy `condition`.
+    pub fn with_condition(mut self, condition: Expr) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// Checks that the current bindings satisfy the condition of the matcher, if any, along with
+    /// every condition produced from within the pattern.
+    fn satisfies_condition(&mut self) -> bool {
+        self.condition
+            .iter()
+            .chain(self.condition_stack.iter())
+            .all(|condition| check_condition(condition, &self.substitutions, self.context))
+    }
+
+    /// Flattens any nested applications of the head of `expr` into `expr`, if that head is
+    /// associative. For example `fa[fa[a, b], c]` becomes `fa[a, b, c]`.
+    fn flatten_associative(&self, expr: Expr) -> Expr {
+        let Some(normal) = expr.try_normal() else {
+            return expr;
+        };
+
+        let Some(head) = normal.try_head_symbol() else {
+            return expr;
+        };
+
+        if !self.context.get_attributes(head).associative()
+            || !normal
+                .elements()
+                .iter()
+                .any(|elem| elem.is_normal_head(head))
+        {
+            return expr;
         }
+
+        let mut elements = Vec::with_capacity(normal.len());
+
+        for elem in normal.elements() {
+            match elem.try_normal_head(head) {
+                Some(nested) => {
+                    let nested = self.flatten_associative(Expr::from(nested.clone()));
+                    elements.extend_from_slice(nested.try_normal().unwrap().elements());
+                }
+                None => elements.push(elem.clone()),
+            }
+        }
+
+        Expr::from(Normal::new(normal.head().clone(), elements))
     }
 
     /// Check which rule applies to the active match equation, creates the match generator for that
     /// rule, and pushes the match generator onto the match stack.
     fn select_rule(&mut self) -> Option<BoxedMatchGenerator> {
         // TODO: Substitute bound variables with their values for the *pattern*.
-        let match_equation = match self.equation_stack.pop() {
+        let mut match_equation = match self.equation_stack.pop() {
             Some(match_equation) => match_equation,
             None => return None,
         };
 
+        // `HoldPattern[p]` matches exactly the same values as `p`.
+        match_equation.pattern = strip_hold_pattern(&match_equation.pattern).clone();
+
+        // Nested applications of an associative head are equivalent to a single application, so
+        // both sides are flattened before any rules are applied.
+        match_equation.pattern = self.flatten_associative(match_equation.pattern);
+        match_equation.ground = self.flatten_associative(match_equation.ground);
+
         if let Some(rule) = RuleT::try_rule(&match_equation) {
             return Some(Box::new(rule));
         }
 
+        // Pattern tests are evaluated up front, as the ground is the value the test applies to.
+        if let Some(rule) = RulePT::try_rule(&match_equation) {
+            if rule.passes(self.context) {
+                return Some(Box::new(rule));
+            }
+
+            self.equation_stack.push(match_equation);
+            return None;
+        }
+
+        if let Some(rule) = RuleExcept::try_rule(&match_equation) {
+            if rule.passes(self.context) {
+                return Some(Box::new(rule));
+            }
+
+            self.equation_stack.push(match_equation);
+            return None;
+        }
+
+        if let Some(rule) = RuleCE::try_rule(&match_equation) {
+            return Some(Box::new(rule));
+        }
+
         if let Some(rule) = RuleVE::try_rule(&match_equation) {
             return Some(Box::new(rule));
         }
@@ -88,9 +184,10 @@ impl<'c> Matcher<'c> {
             match_equation.pattern.try_normal(),
             match_equation.ground.try_normal(),
         ) {
-            // TODO: Need to be able to match `f[...][...]` to `g[...][...]`. Will need to add
-            //  rules to destructure the head in this case. This should be recursive to support
-            //  operations like `f[...][...][...]` etc.
+            // Attempting to match `f[...][...]` with `g[...][...]`, by destructuring the heads.
+            if let Some(rule) = RuleDH::try_rule(&match_equation) {
+                return Some(Box::new(rule));
+            }
 
             // Attempting to match `f[...]` with `g[...]' where `f` and `g` are symbols and match.
             if let (Some(phead), Some(ghead)) = (p.try_head_symbol(), g.try_head_symbol()) {
@@ -213,6 +310,11 @@ impl<'c> Matcher<'c> {
                     let new_length = self.equation_stack.len() - added;
                     self.equation_stack.truncate(new_length);
                 }
+
+                // Remove the produced condition
+                MatchStack::ProducedCondition => {
+                    self.condition_stack.pop();
+                }
             }
         }
     }
@@ -233,6 +335,11 @@ impl<'c> Matcher<'c> {
                     self.equation_stack.push(match_equation);
                     equation_count += 1;
                 }
+
+                MatchResult::Condition(condition) => {
+                    self.condition_stack.push(condition);
+                    self.match_stack.push(MatchStack::ProducedCondition);
+                }
             }
         }
 
@@ -241,16 +348,24 @@ impl<'c> Matcher<'c> {
                 .push(MatchStack::ProducedMatchEquations(equation_count));
         }
     }
-}
 
-impl<'c> Iterator for Matcher<'c> {
-    type Item = SolutionSet;
+    /// Returns the first solution, if any. Unlike `next()` this consumes the matcher, so the
+    /// bindings are moved out rather than cloned.
+    pub fn first(mut self) -> Option<SolutionSet> {
+        if self.advance() {
+            Some(std::mem::take(&mut self.substitutions))
+        } else {
+            None
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Advances the matcher to the next solution, returning whether one was found. On success
+    /// the bindings of the solution are held in `substitutions`.
+    fn advance(&mut self) -> bool {
         // If the last match was successful, the equation stack will be empty. But there could be
         // more solutions possible, in which case backtracking will put equations back on the stack.
         if self.equation_stack.is_empty() && self.match_stack.is_empty() {
-            return None;
+            return false;
         }
 
         'step1: loop {
@@ -268,7 +383,7 @@ impl<'c> Iterator for Matcher<'c> {
                 None => {
                     // If the match stack is empty, halt with failure.
                     if self.match_stack.is_empty() {
-                        return None;
+                        return false;
                     }
 
                     // If there is an active match generator on top of the matcher stack, undo the
@@ -294,9 +409,11 @@ impl<'c> Iterator for Matcher<'c> {
                             Some(results) => {
                                 self.process_match_list(results);
 
-                                // Succeed if the equation stack is empty.
-                                if self.equation_stack.is_empty() {
-                                    return Some(self.substitutions.clone());
+                                // Succeed if the equation stack is empty, and the bindings
+                                // satisfy the condition. Otherwise the empty equation stack
+                                // causes the next step to backtrack.
+                                if self.equation_stack.is_empty() && self.satisfies_condition() {
+                                    return true;
                                 }
 
                                 continue 'step1;
@@ -309,7 +426,7 @@ impl<'c> Iterator for Matcher<'c> {
 
                                 // Fail if there is no previous matcher to backtrack to.
                                 if self.match_stack.is_empty() {
-                                    return None;
+                                    return false;
                                 }
 
                                 let match_generator = self.undo();
@@ -334,12 +451,28 @@ impl<'c> Iterator for Matcher<'c> {
                             me
                         )
                     }
+
+                    Some(MatchStack::ProducedCondition) => {
+                        panic!("Expected a MatchGenerator. Found a produced condition.")
+                    }
                 }
             }
         }
     }
 }
 
+impl<'c> Iterator for Matcher<'c> {
+    type Item = SolutionSet;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.advance() {
+            Some(self.substitutions.clone())
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,12 +503,12 @@ mod tests {
         ($name:ident, $pattern:expr, $ground:expr, []) => {
             #[test]
             fn $name() -> () {
-                let context = create_context();
+                let mut context = create_context();
 
                 let mut matcher = Matcher::new(
                     parse_str($pattern).unwrap(),
                     parse_str($ground).unwrap(),
-                    &context,
+                    &mut context,
                 );
 
                 assert_eq!(matcher.next(), None);
@@ -385,12 +518,12 @@ mod tests {
         ($name:ident, $pattern:expr, $ground:expr, [[]]) => {
             #[test]
             fn $name() -> () {
-                let context = create_context();
+                let mut context = create_context();
 
                 let mut matcher = Matcher::new(
                     parse_str($pattern).unwrap(),
                     parse_str($ground).unwrap(),
-                    &context,
+                    &mut context,
                 );
 
                 assert_eq!(matcher.next(), Some(HashMap::new()));
@@ -401,12 +534,12 @@ mod tests {
         ($name:ident, $pattern:expr, $ground:expr, $expected_solutions:expr) => {
             #[test]
             fn $name() -> () {
-                let context = create_context();
+                let mut context = create_context();
 
                 let mut matcher = Matcher::new(
                     parse_str($pattern).unwrap(),
                     parse_str($ground).unwrap(),
-                    &context,
+                    &mut context,
                 );
 
                 for expected_solution in $expected_solutions {
@@ -435,6 +568,14 @@ mod tests {
         []
     );
 
+    // Mismatched atom kinds
+    matcher_test!(integer_does_not_match_real, "1", "1.0", []);
+    matcher_test!(real_does_not_match_integer, "1.0", "1", []);
+    matcher_test!(string_does_not_match_integer, "\"1\"", "1", []);
+    matcher_test!(integer_does_not_match_string, "1", "\"1\"", []);
+    matcher_test!(string_does_not_match_symbol, "\"abc\"", "abc", []);
+    matcher_test!(nested_integer_does_not_match_real, "f[1]", "f[1.0]", []);
+
     // Solvable with no substitutions
     matcher_test!(matching_strings, "\"abc\"", "\"abc\"", [[]]);
     matcher_test!(matching_integers, "123", "123", [[]]);
@@ -599,6 +740,82 @@ mod tests {
         [[("x", "abc")]]
     );
 
+    // Blanks with head constraints
+    matcher_test!(blank_head_matches_integer, "_Integer", "5", [[]]);
+    matcher_test!(blank_head_rejects_symbol, "_Integer", "abc", []);
+    matcher_test!(
+        named_blank_head_matches_integer,
+        "x_Integer",
+        "5",
+        [[("x", "5")]]
+    );
+    matcher_test!(named_blank_head_rejects_symbol, "x_Integer", "abc", []);
+    matcher_test!(named_blank_head_rejects_real, "x_Integer", "5.0", []);
+    matcher_test!(blank_head_matches_expression, "_f", "f[1]", [[]]);
+    matcher_test!(blank_head_rejects_expression, "_f", "g[1]", []);
+    matcher_test!(
+        named_blank_head_in_head,
+        "x_Symbol[a, b, c]",
+        "abc[a, b, c]",
+        [[("x", "abc")]]
+    );
+    matcher_test!(
+        named_blank_head_in_head_rejected,
+        "x_Integer[a, b, c]",
+        "abc[a, b, c]",
+        []
+    );
+
+    // Held patterns
+    matcher_test!(
+        hold_pattern,
+        "HoldPattern[Plus[x_, 0]]",
+        "Plus[a, 0]",
+        [[("x", "a")]]
+    );
+    matcher_test!(
+        hold_pattern_in_elements,
+        "f[HoldPattern[x_], y_]",
+        "f[a, b]",
+        [[("x", "a"), ("y", "b")]]
+    );
+
+    // Exceptions
+    matcher_test!(except_matches_other_value, "Except[0]", "5", [[]]);
+    matcher_test!(except_rejects_exception, "Except[0]", "0", []);
+    matcher_test!(except_pattern_exception, "Except[_Integer]", "a", [[]]);
+    matcher_test!(except_does_not_bind, "Except[x_Integer]", "a", [[]]);
+    matcher_test!(
+        except_with_pattern,
+        "Except[0, x_Integer]",
+        "5",
+        [[("x", "5")]]
+    );
+    matcher_test!(
+        except_with_pattern_rejects_exception,
+        "Except[0, x_Integer]",
+        "0",
+        []
+    );
+    matcher_test!(
+        except_with_pattern_rejects_pattern,
+        "Except[0, x_Integer]",
+        "a",
+        []
+    );
+    matcher_test!(
+        except_in_elements,
+        "f[x_, Except[0], y_]",
+        "f[1, 2, 3]",
+        [[("x", "1"), ("y", "3")]]
+    );
+    matcher_test!(
+        except_in_elements_rejected,
+        "f[x_, Except[0], y_]",
+        "f[1, 0, 3]",
+        []
+    );
+
     mod free_functions {
         use super::*;
 
@@ -682,6 +899,44 @@ mod tests {
             [[("x", "b"), ("y", "c")]]
         );
 
+        // Named blanks with head constraints
+        matcher_test!(
+            named_blank_head_in_elements,
+            "f[x_Integer, y_Symbol]",
+            "f[1, a]",
+            [[("x", "1"), ("y", "a")]]
+        );
+        matcher_test!(
+            named_blank_head_in_elements_rejected,
+            "f[x_Integer, y_Symbol]",
+            "f[a, 1]",
+            []
+        );
+
+        // Named blank sequences with head constraints
+        matcher_test!(
+            named_blank_sequence_head,
+            "f[x__Integer]",
+            "f[1, 2, 3]",
+            [[("x", "Sequence[1, 2, 3]")]]
+        );
+        matcher_test!(
+            named_blank_sequence_head_rejected,
+            "f[x__Integer]",
+            "f[1, a, 3]",
+            []
+        );
+        matcher_test!(
+            named_blank_sequence_head_prefixes,
+            "f[xs___Integer, ys___]",
+            "f[1, 2, a]",
+            [
+                [("xs", "Sequence[]"), ("ys", "Sequence[1, 2, a]")],
+                [("xs", "Sequence[1]"), ("ys", "Sequence[2, a]")],
+                [("xs", "Sequence[1, 2]"), ("ys", "Sequence[a]")],
+            ]
+        );
+
         // Multiple named blank sequences
         matcher_test!(
             multiple_blank_sequences,
@@ -707,6 +962,57 @@ mod tests {
         );
     }
 
+    mod curried_heads {
+        use super::*;
+
+        // Unsolvable
+        matcher_test!(mismatched_inner_heads, "f[x_][y_]", "g[a][b]", []);
+        matcher_test!(mismatched_outer_elements, "f[x_][b]", "f[a][c]", []);
+        matcher_test!(mismatched_depth, "f[x_][y_]", "f[a]", []);
+        matcher_test!(uncurried_pattern, "f[x_]", "f[a][b]", []);
+
+        // Trivial cases
+        matcher_test!(exact_match, "f[a][b]", "f[a][b]", [[]]);
+
+        // Named blanks
+        matcher_test!(
+            named_blanks,
+            "f[x_][y_]",
+            "f[a][b]",
+            [[("x", "a"), ("y", "b")]]
+        );
+        matcher_test!(repeated_named_blank, "f[x_][x_]", "f[a][a]", [[("x", "a")]]);
+        matcher_test!(
+            nested_heads,
+            "f[x_][y_][z_]",
+            "f[a][b][c]",
+            [[("x", "a"), ("y", "b"), ("z", "c")]]
+        );
+        matcher_test!(
+            head_pattern,
+            "h_[y_]",
+            "f[a][b]",
+            [[("h", "f[a]"), ("y", "b")]]
+        );
+
+        // Sequences
+        matcher_test!(
+            sequences,
+            "f[x__][y___]",
+            "f[a, b][]",
+            [[("x", "Sequence[a, b]"), ("y", "Sequence[]")]]
+        );
+
+        // Attributes of the innermost head only apply to its own elements
+        matcher_test!(
+            commutative_inner_head,
+            "fc[x_, b][y_, d]",
+            "fc[b, a][c, d]",
+            [[("x", "a"), ("y", "c")]]
+        );
+        matcher_test!(free_outer_elements, "fc[x_][y_, d]", "fc[a][d, c]", []);
+    }
+
     mod commutative {
         use super::*;
 
@@ -742,6 +1048,20 @@ mod tests {
             [[("x", "c")]]
         );
 
+        // Named blank sequences with head constraints
+        matcher_test!(
+            named_blank_sequence_head,
+            "fc[xs__Integer, a]",
+            "fc[1, a, 2]",
+            [[("xs", "Sequence[1, 2]")], [("xs", "Sequence[2, 1]")]]
+        );
+        matcher_test!(
+            named_blank_sequence_head_rejected,
+            "fc[xs__Integer]",
+            "fc[1, a, 2]",
+            []
+        );
+
         // Two named blank variables
         matcher_test!(
             two_named_blanks_1,
@@ -830,6 +1150,30 @@ mod tests {
         // Exact and application-independent matches
         matcher_test!(blank_null_seq_empty, "fa[___]", "fa[]", [[]]);
         matcher_test!(exact_match, "fa[a, b, c]", "fa[a, b, c]", [[]]);
+        matcher_test!(
+            application_independent_match_1,
+            "fa[fa[a, b], c]",
+            "fa[a, b, c]",
+            [[]]
+        );
+        matcher_test!(
+            application_independent_match_2,
+            "fa[a, b, c]",
+            "fa[fa[a, b], c]",
+            [[]]
+        );
+        matcher_test!(
+            application_independent_match_3,
+            "fa[a, fa[b, c]]",
+            "fa[a, b, c]",
+            [[]]
+        );
+        matcher_test!(
+            nested_application_independent_match,
+            "fa[x_, c]",
+            "fa[fa[a, fa[b]], c]",
+            [[("x", "fa[a, b]")]]
+        );
 
         // Single named blank variables
         matcher_test!(
@@ -852,6 +1196,34 @@ mod tests {
             [[("x", "fa[a, b]")]]
         );
 
+        // Named blank variables with head constraints
+        matcher_test!(
+            named_blank_head_singleton,
+            "fa[x_Symbol, y_]",
+            "fa[a, b]",
+            [[("x", "a"), ("y", "b")], [("x", "a"), ("y", "fa[b]")]]
+        );
+        matcher_test!(
+            named_blank_head_application,
+            "fa[x_fa, c]",
+            "fa[a, b, c]",
+            [[("x", "fa[a, b]")]]
+        );
+        matcher_test!(
+            named_blank_head_rejected,
+            "fa[x_Integer, c]",
+            "fa[a, b, c]",
+            []
+        );
+
+        // Named blank sequences with head constraints
+        matcher_test!(
+            named_blank_sequence_head,
+            "fa[xs__Symbol, c]",
+            "fa[a, b, c]",
+            [[("xs", "Sequence[a, b]")]]
+        );
+
         // Two named blank variables
         matcher_test!(two_named_blanks_1, "fa[x_, y_]", "fa[a]", []);
         matcher_test!(
@@ -1185,6 +1557,31 @@ mod tests {
             ]
         );
 
+        // Sequence variables matching a single element from within the ground
+        matcher_test!(
+            blank_sequence_middle_element,
+            "fac[xs__, a, c]",
+            "fac[a, b, c]",
+            [[("xs", "Sequence[b]")], [("xs", "Sequence[fac[b]]")]]
+        );
+        matcher_test!(
+            blank_sequence_middle_elements,
+            "fac[xs__, a, d]",
+            "fac[a, b, c, d]",
+            [
+                [("xs", "Sequence[b, c]")],
+                [("xs", "Sequence[fac[b], c]")],
+                [("xs", "Sequence[b, fac[c]]")],
+                [("xs", "Sequence[fac[b], fac[c]]")],
+                [("xs", "Sequence[fac[b, c]]")],
+                [("xs", "Sequence[c, b]")],
+                [("xs", "Sequence[fac[c], b]")],
+                [("xs", "Sequence[c, fac[b]]")],
+                [("xs", "Sequence[fac[c], fac[b]]")],
+                [("xs", "Sequence[fac[c, b]]")],
+            ]
+        );
+
         // Multiple blank null sequence variables
         matcher_test!(
             multiple_blank_null_sequences,
@@ -1612,4 +2009,95 @@ mod tests {
             ]
         );
     }
+
+    mod first {
+        use super::*;
+
+        fn assert_first_matches_iterator(pattern: &str, ground: &str) {
+            let mut context = create_context();
+
+            let expected = Matcher::new(
+                parse_str(pattern).unwrap(),
+                parse_str(ground).unwrap(),
+                &mut context,
+            )
+            .next();
+
+            let actual = Matcher::new(
+                parse_str(pattern).unwrap(),
+                parse_str(ground).unwrap(),
+                &mut context,
+            )
+            .first();
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn no_solutions() {
+            assert_first_matches_iterator("f[a, b]", "f[a, c]");
+        }
+
+        #[test]
+        fn free_function() {
+            assert_first_matches_iterator("f[xs__, ys__]", "f[a, b, c]");
+        }
+
+        #[test]
+        fn commutative() {
+            assert_first_matches_iterator("fc[x_, ys__]", "fc[a, b, c]");
+        }
+
+        #[test]
+        fn associative_commutative() {
+            assert_first_matches_iterator("fac[xs__, ys__]", "fac[a, b, c]");
+        }
+
+        #[test]
+        fn large_commutative_ground() {
+            let mut context = create_context();
+
+            // Enumerating every solution would visit all 12! orderings of the ground.
+            let solution = Matcher::new(
+                parse_str("fc[x_, ys__]").unwrap(),
+                parse_str("fc[a, b, c, d, e, f, g, h, i, j, k, l]").unwrap(),
+                &mut context,
+            )
+            .first();
+
+            assert_eq!(
+                solution,
+                Some(HashMap::from([
+                    (Symbol::new("x"), parse_str("a").unwrap()),
+                    (
+                        Symbol::new("ys"),
+                        parse_str("Sequence[b, c, d, e, f, g, h, i, j, k, l]").unwrap()
+                    ),
+                ]))
+            );
+        }
+    }
+
+    mod bench {
+        use super::*;
+
+        /// Run with `time cargo test --release -- --ignored bench` to time the matcher, which must
+        /// find every way of choosing `x` and `y` and ordering the remaining six elements. This
+        /// only measures time, not allocations; interning an already seen symbol name does not
+        /// allocate, so looking up builtin symbols costs hashing rather than allocation.
+        #[test]
+        #[ignore]
+        fn large_commutative_match() {
+            let mut context = create_context();
+
+            let count = Matcher::new(
+                parse_str("fc[x_, y_, zs___]").unwrap(),
+                parse_str("fc[a, b, c, d, e, f, g, h]").unwrap(),
+                &mut context,
+            )
+            .count();
+
+            assert_eq!(count, 40320);
+        }
+    }
 }
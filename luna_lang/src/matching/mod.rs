@@ -1,19 +1,24 @@
 mod function_application;
 mod matcher;
 mod permutations;
+mod rule_ce;
 mod rule_dc;
+mod rule_dh;
 mod rule_dnc;
+mod rule_except;
 mod rule_fve;
 mod rule_fvea;
 mod rule_fveac;
 mod rule_ivea;
 mod rule_iveac;
+mod rule_pt;
 mod rule_svea;
 mod rule_sveac;
 mod rule_svec;
 mod rule_svef;
 mod rule_t;
 mod rule_ve;
+mod specificity;
 mod subsets;
 
 use std::collections::HashMap;
@@ -22,6 +27,7 @@ use crate::Expr;
 use crate::Symbol;
 
 pub use matcher::Matcher;
+pub use specificity::compare_specificity;
 
 pub type SolutionSet = HashMap<Symbol, Expr>;
 
@@ -49,6 +55,7 @@ pub type MatchResultList = Vec<MatchResult>;
 pub enum MatchResult {
     MatchEquation(MatchEquation),
     Substitution(Substitution),
+    Condition(Expr),
 }
 
 /// A `MatchGenerator` iterates over every way it can transform a match equation and
@@ -43,6 +43,9 @@ pub(crate) struct PermutationGenerator32 {
 }
 
 impl PermutationGenerator32 {
+    /// Creates a generator over every permutation of `nb_elems` elements. At most 32 elements are
+    /// supported, being the capacity of `BitIndex32`. The number of permutations always fits within
+    /// a `u128` for this range, although it exceeds `usize` above 20 elements.
     pub fn new(nb_elems: u8) -> Self {
         Self {
             next_idx: 0,
@@ -61,12 +64,79 @@ impl Iterator for PermutationGenerator32 {
         self.next_idx = step_result + 1;
         res
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let nb_remaining = self.nb_perms.saturating_sub(self.next_idx);
+
+        match usize::try_from(nb_remaining) {
+            Ok(nb_remaining) => (nb_remaining, Some(nb_remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
 }
 
 #[inline]
 pub(crate) fn factorial128(nb_elems: u8) -> u128 {
     match nb_elems {
         0 | 1 | 2 => nb_elems as u128,
-        _ => (1..=nb_elems).map(|i| i as u128).product(),
+        _ => (1..=nb_elems)
+            .try_fold(1u128, |acc, i| acc.checked_mul(i as u128))
+            .unwrap_or(u128::MAX),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_all_permutations() {
+        let permutations = PermutationGenerator32::new(3)
+            .map(|permutation| permutation.collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            permutations,
+            vec![
+                vec![0, 1, 2],
+                vec![0, 2, 1],
+                vec![1, 0, 2],
+                vec![1, 2, 0],
+                vec![2, 0, 1],
+                vec![2, 1, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn size_hint_counts_remaining_permutations() {
+        let mut permutations = PermutationGenerator32::new(4);
+        assert_eq!(permutations.size_hint(), (24, Some(24)));
+
+        permutations.next();
+        assert_eq!(permutations.size_hint(), (23, Some(23)));
+    }
+
+    #[test]
+    fn large_number_of_elements() {
+        let mut permutations = PermutationGenerator32::new(25);
+        assert_eq!(permutations.size_hint(), (usize::MAX, None));
+
+        for permutation in permutations.by_ref().take(100) {
+            let mut indices = permutation.collect::<Vec<_>>();
+            indices.sort();
+
+            assert_eq!(indices, (0..25).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn factorial_saturates() {
+        assert_eq!(factorial128(20), 2_432_902_008_176_640_000);
+        assert_eq!(
+            factorial128(34),
+            295_232_799_039_604_140_847_618_609_643_520_000_000
+        );
+        assert_eq!(factorial128(35), u128::MAX);
     }
 }
@@ -0,0 +1,66 @@
+use crate::{Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, sym};
+
+/// Condition elimination.
+///
+/// Matches a pattern `Condition[p, test]` against a value `g`, producing the match equation `p`
+/// against `g`. The `test` is deferred until the match is complete, at which point it must
+/// evaluate to `True` given the final bindings.
+pub(crate) struct RuleCE {
+    match_equation: MatchEquation,
+    pattern: Expr,
+    test: Expr,
+    exhausted: bool,
+}
+
+impl RuleCE {
+    pub(crate) fn new(match_equation: MatchEquation, pattern: Expr, test: Expr) -> Self {
+        Self {
+            match_equation,
+            pattern,
+            test,
+            exhausted: false,
+        }
+    }
+}
+
+impl MatchRule for RuleCE {
+    fn try_rule(match_equation: &MatchEquation) -> Option<Self> {
+        let p = match_equation.pattern.try_normal_head(&sym!(Condition))?;
+
+        if p.len() != 2 {
+            return None;
+        }
+
+        Some(Self::new(
+            match_equation.clone(),
+            p.element(0)?.clone(),
+            p.element(1)?.clone(),
+        ))
+    }
+}
+
+impl MatchGenerator for RuleCE {
+    fn match_equation(&self) -> MatchEquation {
+        self.match_equation.clone()
+    }
+}
+
+impl Iterator for RuleCE {
+    type Item = MatchResultList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        self.exhausted = true;
+
+        Some(vec![
+            MatchResult::MatchEquation(MatchEquation {
+                pattern: self.pattern.clone(),
+                ground: self.match_equation.ground.clone(),
+            }),
+            MatchResult::Condition(self.test.clone()),
+        ])
+    }
+}
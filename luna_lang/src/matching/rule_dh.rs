@@ -0,0 +1,76 @@
+use crate::{
+    Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Normal, sym,
+};
+
+/// Decomposition of a compound head.
+///
+/// Matches a pattern `p[x, ...]` against a value `g[y, ...]` where both `p` and `g` are themselves
+/// expressions, for example `f[n_][x_]` against `f[1][2]`. The heads are matched against each
+/// other, and the elements are matched as the elements of a free function. Nested heads such as
+/// `f[...][...][...]` are decomposed again when the heads are matched.
+///
+/// Assumptions:
+/// - `p` and `g` are both normal expressions.
+pub(crate) struct RuleDH {
+    pattern: Normal,
+    ground: Normal,
+    exhausted: bool,
+}
+
+impl RuleDH {
+    pub(crate) fn new(pattern: Normal, ground: Normal) -> Self {
+        Self {
+            pattern,
+            ground,
+            exhausted: false,
+        }
+    }
+}
+
+impl MatchRule for RuleDH {
+    fn try_rule(match_equation: &MatchEquation) -> Option<Self> {
+        let p = match_equation.pattern.try_normal()?;
+        let g = match_equation.ground.try_normal()?;
+
+        if p.head().try_normal().is_none() || g.head().try_normal().is_none() {
+            return None;
+        }
+
+        Some(Self::new(p.clone(), g.clone()))
+    }
+}
+
+impl MatchGenerator for RuleDH {
+    fn match_equation(&self) -> MatchEquation {
+        MatchEquation {
+            pattern: Expr::from(self.pattern.clone()),
+            ground: Expr::from(self.ground.clone()),
+        }
+    }
+}
+
+impl Iterator for RuleDH {
+    type Item = MatchResultList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        // Match equation to attempt to match `p` and `g`.
+        let result_head_equation = MatchResult::MatchEquation(MatchEquation {
+            pattern: self.pattern.head().clone(),
+            ground: self.ground.head().clone(),
+        });
+
+        // Match equation to attempt to match the elements. A compound head has no attributes, so
+        // the elements are placed under `List` in order to be matched as a free function.
+        let result_elements_equation = MatchResult::MatchEquation(MatchEquation {
+            pattern: Expr::from(Normal::new(sym!(List), self.pattern.elements())),
+            ground: Expr::from(Normal::new(sym!(List), self.ground.elements())),
+        });
+
+        self.exhausted = true;
+        Some(vec![result_head_equation, result_elements_equation])
+    }
+}
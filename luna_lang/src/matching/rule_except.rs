@@ -0,0 +1,86 @@
+use crate::{
+    Context, Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Matcher,
+    sym,
+};
+
+/// Exception elimination.
+///
+/// Matches a pattern `Except[c]` against a value `g` when `c` does not match `g`, or a pattern
+/// `Except[c, p]` against a value `g` when `c` does not match `g` but `p` does. Any variables
+/// within `c` are never bound. The rule only applies when `c` does not match `g`, which must be
+/// checked via `passes` before the rule is used.
+pub(crate) struct RuleExcept {
+    match_equation: MatchEquation,
+    exception: Expr,
+    pattern: Option<Expr>,
+    exhausted: bool,
+}
+
+impl RuleExcept {
+    pub(crate) fn new(
+        match_equation: MatchEquation,
+        exception: Expr,
+        pattern: Option<Expr>,
+    ) -> Self {
+        Self {
+            match_equation,
+            exception,
+            pattern,
+            exhausted: false,
+        }
+    }
+
+    /// Attempts to match the exception against `g` using a separate matcher, returning whether
+    /// there are no solutions.
+    pub(crate) fn passes(&self, context: &mut Context) -> bool {
+        Matcher::new(
+            self.exception.clone(),
+            self.match_equation.ground.clone(),
+            context,
+        )
+        .first()
+        .is_none()
+    }
+}
+
+impl MatchRule for RuleExcept {
+    fn try_rule(match_equation: &MatchEquation) -> Option<Self> {
+        let p = match_equation.pattern.try_normal_head(&sym!(Except))?;
+
+        match p.elements() {
+            [exception] => Some(Self::new(match_equation.clone(), exception.clone(), None)),
+            [exception, pattern] => Some(Self::new(
+                match_equation.clone(),
+                exception.clone(),
+                Some(pattern.clone()),
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl MatchGenerator for RuleExcept {
+    fn match_equation(&self) -> MatchEquation {
+        self.match_equation.clone()
+    }
+}
+
+impl Iterator for RuleExcept {
+    type Item = MatchResultList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        self.exhausted = true;
+
+        match &self.pattern {
+            None => Some(vec![]),
+            Some(pattern) => Some(vec![MatchResult::MatchEquation(MatchEquation {
+                pattern: pattern.clone(),
+                ground: self.match_equation.ground.clone(),
+            })]),
+        }
+    }
+}
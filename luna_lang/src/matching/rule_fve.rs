@@ -2,7 +2,7 @@ use crate::Normal;
 use crate::Symbol;
 use crate::{
     Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Substitution,
-    parse_individual_variable,
+    matches_head_constraint, parse_individual_variable,
 };
 
 /// Function variable elimination.
@@ -31,9 +31,11 @@ impl MatchRule for RuleFVE {
         let p = match_equation.pattern.try_normal()?;
         let g = match_equation.ground.try_normal()?;
 
-        let (variable, _) = parse_individual_variable(p.head())?;
+        let (variable, h) = parse_individual_variable(p.head())?;
 
-        // TODO: Evaluate constraints for `Blank[h]` and `Pattern[_, Blank[h]]`.
+        if !matches_head_constraint(g.head(), h) {
+            return None;
+        }
 
         Some(Self::new(p.clone(), g.clone(), variable.cloned()))
     }
@@ -1,7 +1,7 @@
 use crate::matching::rule_dc::RuleDC;
 use crate::{
     Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Normal,
-    Substitution, Symbol, parse_individual_variable,
+    Substitution, Symbol, matches_head_constraint, parse_individual_variable,
 };
 
 /// Function variable elimination under an associative-commutative head.
@@ -48,16 +48,20 @@ impl MatchRule for RuleFVEAC {
 
         let (p_elem0, _) = (p.element(0)?, g.element(0)?);
         let p_elem0_normal = p_elem0.try_normal()?;
-        let (variable, _) = parse_individual_variable(p_elem0_normal.head())?;
+        let (variable, h) = parse_individual_variable(p_elem0_normal.head())?;
 
-        // TODO: Evaluate constraints for `Blank[h]` and `Pattern[_, Blank[h]]`.
-
-        Some(Self::new(
+        let mut rule = Self::new(
             p.clone(),
             p_elem0_normal.clone(),
             g.clone(),
             variable.cloned(),
-        ))
+        );
+
+        // The variable is bound to the head of the ground, so can only be eliminated if that
+        // satisfies the head constraint. Decomposition results are still produced.
+        rule.exhausted = !matches_head_constraint(g.head(), h);
+
+        Some(rule)
     }
 }
 
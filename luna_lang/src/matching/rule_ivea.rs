@@ -28,6 +28,7 @@ pub(crate) struct RuleIVEA {
     ground: Normal,
     rule_dnc: RuleDNC,
     rule_svef: RuleSVEF,
+    applications: bool,
 }
 
 impl RuleIVEA {
@@ -36,7 +37,14 @@ impl RuleIVEA {
             pattern: pattern.clone(),
             ground: ground.clone(),
             rule_dnc: RuleDNC::new(pattern.clone(), ground.clone()),
-            rule_svef: RuleSVEF::new(pattern.clone(), ground.clone(), variable.clone(), false),
+            rule_svef: RuleSVEF::new(
+                pattern.clone(),
+                ground.clone(),
+                variable.clone(),
+                None,
+                false,
+            ),
+            applications: true,
         }
     }
 }
@@ -47,11 +55,15 @@ impl MatchRule for RuleIVEA {
         let g = match_equation.ground.try_normal()?;
 
         let (p_elem0, _) = (p.element(0)?, g.element(0)?);
-        let (variable, _) = parse_individual_variable(p_elem0)?;
+        let (variable, h) = parse_individual_variable(p_elem0)?;
 
-        // TODO: Evaluate constraints for `Blank[h]` and `Pattern[_, Blank[h]]`.
+        let mut rule = Self::new(p.clone(), g.clone(), variable.cloned());
 
-        Some(Self::new(p.clone(), g.clone(), variable.cloned()))
+        // Function applications share the head of the ground, so are only produced if that
+        // satisfies the head constraint. Singleton results are checked when they are matched.
+        rule.applications = h.is_none_or(|h| g.head() == h);
+
+        Some(rule)
     }
 }
 
@@ -74,6 +86,10 @@ impl Iterator for RuleIVEA {
             return Some(result);
         }
 
+        if !self.applications {
+            return None;
+        }
+
         // Next attempt to get the next result from `RuleSVEF`.
         let result = self.rule_svef.next()?;
 
@@ -31,6 +31,7 @@ pub(crate) struct RuleIVEAC {
     ground: Normal,
     rule_dc: RuleDC,
     rule_svec: RuleSVEC,
+    applications: bool,
 }
 
 impl RuleIVEAC {
@@ -39,7 +40,14 @@ impl RuleIVEAC {
             pattern: pattern.clone(),
             ground: ground.clone(),
             rule_dc: RuleDC::new(pattern.clone(), ground.clone()),
-            rule_svec: RuleSVEC::new(pattern.clone(), ground.clone(), variable.clone(), false),
+            rule_svec: RuleSVEC::new(
+                pattern.clone(),
+                ground.clone(),
+                variable.clone(),
+                None,
+                false,
+            ),
+            applications: true,
         }
     }
 }
@@ -50,11 +58,15 @@ impl MatchRule for RuleIVEAC {
         let g = match_equation.ground.try_normal()?;
 
         let (p_elem0, _) = (p.element(0)?, g.element(0)?);
-        let (variable, _) = parse_individual_variable(p_elem0)?;
+        let (variable, h) = parse_individual_variable(p_elem0)?;
 
-        // TODO: Evaluate constraints for `Blank[h]` and `Pattern[_, Blank[h]]`.
+        let mut rule = Self::new(p.clone(), g.clone(), variable.cloned());
 
-        Some(Self::new(p.clone(), g.clone(), variable.cloned()))
+        // Function applications share the head of the ground, so are only produced if that
+        // satisfies the head constraint. Singleton results are checked when they are matched.
+        rule.applications = h.is_none_or(|h| g.head() == h);
+
+        Some(rule)
     }
 }
 
@@ -77,6 +89,10 @@ impl Iterator for RuleIVEAC {
             return Some(result);
         }
 
+        if !self.applications {
+            return None;
+        }
+
         // Next attempt to get the next result from `RuleSVEC`.
         let result = self.rule_svec.next()?;
 
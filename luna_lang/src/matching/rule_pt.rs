@@ -0,0 +1,77 @@
+use crate::{
+    Context, Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Normal,
+    evaluate, sym,
+};
+
+/// Pattern test elimination.
+///
+/// Matches a pattern `PatternTest[p, test]` against a value `g`, producing the match equation
+/// `p` against `g`. The rule only applies when `test[g]` evaluates to `True`, which must be
+/// checked via `passes` before the rule is used.
+pub(crate) struct RulePT {
+    match_equation: MatchEquation,
+    pattern: Expr,
+    test: Expr,
+    exhausted: bool,
+}
+
+impl RulePT {
+    pub(crate) fn new(match_equation: MatchEquation, pattern: Expr, test: Expr) -> Self {
+        Self {
+            match_equation,
+            pattern,
+            test,
+            exhausted: false,
+        }
+    }
+
+    /// Evaluates `test[g]`, returning whether the result is `True`. A test which fails to evaluate
+    /// does not pass.
+    pub(crate) fn passes(&self, context: &mut Context) -> bool {
+        let test = Expr::from(Normal::new(
+            self.test.clone(),
+            vec![self.match_equation.ground.clone()],
+        ));
+
+        evaluate(test, context).is_ok_and(|result| result.try_symbol() == Some(&sym!(True)))
+    }
+}
+
+impl MatchRule for RulePT {
+    fn try_rule(match_equation: &MatchEquation) -> Option<Self> {
+        let p = match_equation.pattern.try_normal_head(&sym!(PatternTest))?;
+
+        if p.len() != 2 {
+            return None;
+        }
+
+        Some(Self::new(
+            match_equation.clone(),
+            p.element(0)?.clone(),
+            p.element(1)?.clone(),
+        ))
+    }
+}
+
+impl MatchGenerator for RulePT {
+    fn match_equation(&self) -> MatchEquation {
+        self.match_equation.clone()
+    }
+}
+
+impl Iterator for RulePT {
+    type Item = MatchResultList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        self.exhausted = true;
+
+        Some(vec![MatchResult::MatchEquation(MatchEquation {
+            pattern: self.pattern.clone(),
+            ground: self.match_equation.ground.clone(),
+        })])
+    }
+}
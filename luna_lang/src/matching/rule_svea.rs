@@ -1,7 +1,7 @@
 use crate::matching::function_application::{AFAGenerator, FunctionApplicationGenerator};
 use crate::{
     Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Normal,
-    Substitution, Symbol, parse_any_sequence_variable, sym,
+    Substitution, Symbol, matches_head_constraint, parse_any_sequence_variable, sym,
 };
 
 /// Sequence variable elimination under an associative head.
@@ -17,6 +17,9 @@ pub(crate) struct RuleSVEA {
     ground: Normal,
     variable: Option<Symbol>,
 
+    /// Optional head constraint which every element of the sequence must satisfy.
+    head: Option<Expr>,
+
     /// Holds the terms of the ground that we have attempted to match against so far.
     ground_sequence: Vec<Expr>,
 
@@ -30,6 +33,7 @@ impl RuleSVEA {
         pattern: Normal,
         ground: Normal,
         variable: Option<Symbol>,
+        head: Option<Expr>,
         matches_empty: bool,
     ) -> Self {
         let afa_generator = if matches_empty {
@@ -45,6 +49,7 @@ impl RuleSVEA {
             pattern,
             ground,
             variable,
+            head,
             ground_sequence: Vec::new(),
             afa_generator,
         }
@@ -83,9 +88,7 @@ impl MatchRule for RuleSVEA {
         let g = match_equation.ground.try_normal()?;
 
         let p_elem0 = p.element(0)?;
-        let (matches_empty, variable, _) = parse_any_sequence_variable(p_elem0)?;
-
-        // TODO: Evaluate constraints for `BlankSequence[h]` and `Pattern[_, BlankSequence[h]]`.
+        let (matches_empty, variable, h) = parse_any_sequence_variable(p_elem0)?;
 
         // If we are the final part of the pattern then it only makes sense to start looking for
         // matches starting with the contents of the ground.
@@ -98,6 +101,7 @@ impl MatchRule for RuleSVEA {
             p.clone(),
             g.clone(),
             variable.cloned(),
+            h.cloned(),
             matches_empty,
         ))
     }
@@ -112,10 +116,9 @@ impl MatchGenerator for RuleSVEA {
     }
 }
 
-impl Iterator for RuleSVEA {
-    type Item = MatchResultList;
-
-    fn next(&mut self) -> Option<Self::Item> {
+impl RuleSVEA {
+    /// Produces the next sequence, irrespective of any head constraint.
+    fn next_sequence(&mut self) -> Option<Vec<Expr>> {
         match &mut self.afa_generator {
             // Current generator being `None` is the signal we need to produce an empty sequence.
             None => {
@@ -124,7 +127,7 @@ impl Iterator for RuleSVEA {
                     vec![],
                 ))));
 
-                Some(self.make_next(Vec::new()))
+                Some(Vec::new())
             }
 
             // Otherwise generate the next result.
@@ -156,8 +159,26 @@ impl Iterator for RuleSVEA {
                     Some(next_result) => next_result,
                 };
 
+                Some(ordered_sequence)
+            }
+        }
+    }
+}
+
+impl Iterator for RuleSVEA {
+    type Item = MatchResultList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ordered_sequence = self.next_sequence()?;
+
+            // Skip any sequences containing elements which do not satisfy the head constraint.
+            if ordered_sequence
+                .iter()
+                .all(|elem| matches_head_constraint(elem, self.head.as_ref()))
+            {
                 // Transform the sequence into a result.
-                Some(self.make_next(ordered_sequence))
+                return Some(self.make_next(ordered_sequence));
             }
         }
     }
@@ -2,7 +2,7 @@ use crate::matching::function_application::{AFACGenerator, FunctionApplicationGe
 use crate::matching::subsets::Subset;
 use crate::{
     Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Normal,
-    Substitution, Symbol, parse_any_sequence_variable, sym,
+    Substitution, Symbol, matches_head_constraint, parse_any_sequence_variable, sym,
 };
 
 /// Sequence variable elimination under an associative-commutative head.
@@ -18,6 +18,9 @@ pub(crate) struct RuleSVEAC {
     ground: Normal,
     variable: Option<Symbol>,
 
+    /// Optional head constraint which every element of the sequence must satisfy.
+    head: Option<Expr>,
+
     /// Current subset of the grounds arguments which are being matched against.
     subset: Subset,
 
@@ -34,6 +37,7 @@ impl RuleSVEAC {
         pattern: Normal,
         ground: Normal,
         variable: Option<Symbol>,
+        head: Option<Expr>,
         matches_empty: bool,
     ) -> Self {
         let subset = Subset::empty(ground.len());
@@ -51,6 +55,7 @@ impl RuleSVEAC {
             pattern,
             ground,
             variable,
+            head,
             subset,
             complement: vec![],
             afac_generator,
@@ -90,9 +95,7 @@ impl MatchRule for RuleSVEAC {
         let g = match_equation.ground.try_normal()?;
 
         let p_elem0 = p.element(0)?;
-        let (matches_empty, variable, _) = parse_any_sequence_variable(p_elem0)?;
-
-        // TODO: Evaluate constraints for `BlankSequence[h]` and `Pattern[_, BlankSequence[h]]`.
+        let (matches_empty, variable, h) = parse_any_sequence_variable(p_elem0)?;
 
         // If we are the final part of the pattern then it only makes sense to start looking for
         // matches starting with the contents of the ground.
@@ -102,6 +105,7 @@ impl MatchRule for RuleSVEAC {
                 pattern: p.clone(),
                 ground: g.clone(),
                 variable: variable.cloned(),
+                head: h.cloned(),
                 subset: Subset::full(g.len()),
                 complement: vec![],
                 afac_generator: Some(Box::new(AFACGenerator::new(g.clone()))),
@@ -112,6 +116,7 @@ impl MatchRule for RuleSVEAC {
             p.clone(),
             g.clone(),
             variable.cloned(),
+            h.cloned(),
             matches_empty,
         ))
     }
@@ -126,10 +131,9 @@ impl MatchGenerator for RuleSVEAC {
     }
 }
 
-impl Iterator for RuleSVEAC {
-    type Item = MatchResultList;
-
-    fn next(&mut self) -> Option<Self::Item> {
+impl RuleSVEAC {
+    /// Produces the next sequence, irrespective of any head constraint.
+    fn next_sequence(&mut self) -> Option<Vec<Expr>> {
         match &mut self.afac_generator {
             // Current generator being `None` is the signal we need to produce an empty sequence.
             None => {
@@ -140,7 +144,7 @@ impl Iterator for RuleSVEAC {
                     vec![],
                 ))));
 
-                Some(self.make_next(Vec::new()))
+                Some(Vec::new())
             }
 
             // Otherwise generate the next result.
@@ -173,8 +177,26 @@ impl Iterator for RuleSVEAC {
                     Some(next_result) => next_result,
                 };
 
+                Some(ordered_sequence)
+            }
+        }
+    }
+}
+
+impl Iterator for RuleSVEAC {
+    type Item = MatchResultList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ordered_sequence = self.next_sequence()?;
+
+            // Skip any sequences containing elements which do not satisfy the head constraint.
+            if ordered_sequence
+                .iter()
+                .all(|elem| matches_head_constraint(elem, self.head.as_ref()))
+            {
                 // Transform the sequence into a result.
-                Some(self.make_next(ordered_sequence))
+                return Some(self.make_next(ordered_sequence));
             }
         }
     }
@@ -2,7 +2,7 @@ use crate::matching::permutations::PermutationGenerator32;
 use crate::matching::subsets::Subset;
 use crate::{
     Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Normal,
-    Substitution, Symbol, parse_any_sequence_variable, sym,
+    Substitution, Symbol, matches_head_constraint, parse_any_sequence_variable, sym,
 };
 
 /// Sequence variable elimination under a commutative head.
@@ -18,6 +18,9 @@ pub(crate) struct RuleSVEC {
     ground: Normal,
     variable: Option<Symbol>,
 
+    /// Optional head constraint which every element of the sequence must satisfy.
+    head: Option<Expr>,
+
     /// Have we produced the empty sequence as the first result yet?
     empty_produced: bool,
 
@@ -33,6 +36,7 @@ impl RuleSVEC {
         pattern: Normal,
         ground: Normal,
         variable: Option<Symbol>,
+        head: Option<Expr>,
         matches_empty: bool,
     ) -> Self {
         let subset = Subset::empty(ground.len());
@@ -42,6 +46,7 @@ impl RuleSVEC {
             pattern,
             ground,
             variable,
+            head,
             empty_produced: !matches_empty,
             subset,
             permutations,
@@ -78,9 +83,7 @@ impl MatchRule for RuleSVEC {
         let g = match_equation.ground.try_normal()?;
 
         let p_elem0 = p.element(0)?;
-        let (matches_empty, variable, _) = parse_any_sequence_variable(p_elem0)?;
-
-        // TODO: Evaluate constraints for `BlankSequence[h]` and `Pattern[_, BlankSequence[h]]`.
+        let (matches_empty, variable, h) = parse_any_sequence_variable(p_elem0)?;
 
         // If we are the final part of the pattern then it only makes sense to start looking for
         // matches starting with the contents of the ground.
@@ -90,6 +93,7 @@ impl MatchRule for RuleSVEC {
                 pattern: p.clone(),
                 ground: g.clone(),
                 variable: variable.cloned(),
+                head: h.cloned(),
                 empty_produced: true,
                 subset: Subset::full(g.len()),
                 permutations: PermutationGenerator32::new(g.len() as u8),
@@ -100,6 +104,7 @@ impl MatchRule for RuleSVEC {
             p.clone(),
             g.clone(),
             variable.cloned(),
+            h.cloned(),
             matches_empty,
         ))
     }
@@ -130,27 +135,37 @@ impl Iterator for RuleSVEC {
             self.subset = self.subset.next()?;
         }
 
-        // Try and get the next permutation for `ground`s elements.
-        let permutation = match self.permutations.next() {
-            Some(permutation) => permutation,
-            None => {
-                self.subset = self.subset.next()?;
-                self.permutations = PermutationGenerator32::new(self.subset.count_ones() as u8);
-                self.permutations.next()?
+        loop {
+            // Try and get the next permutation for `ground`s elements.
+            let permutation = match self.permutations.next() {
+                Some(permutation) => permutation,
+                None => {
+                    self.subset = self.subset.next()?;
+                    self.permutations = PermutationGenerator32::new(self.subset.count_ones() as u8);
+                    self.permutations.next()?
+                }
+            };
+
+            // Extract the subset and complement from the current subset
+            let (subset, complement) = self.subset.extract(self.ground.elements());
+
+            // Skip any subsets containing elements which do not satisfy the head constraint.
+            if !subset
+                .iter()
+                .all(|elem| matches_head_constraint(elem, self.head.as_ref()))
+            {
+                continue;
             }
-        };
-
-        // Extract the subset and complement from the current subset
-        let (subset, complement) = self.subset.extract(self.ground.elements());
-
-        // Create the next result
-        Some(
-            self.make_next(
-                permutation
-                    .map(|idx| subset[idx].clone())
-                    .collect::<Vec<_>>(),
-                complement,
-            ),
-        )
+
+            // Create the next result
+            return Some(
+                self.make_next(
+                    permutation
+                        .map(|idx| subset[idx].clone())
+                        .collect::<Vec<_>>(),
+                    complement,
+                ),
+            );
+        }
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     Expr, MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Normal,
-    Substitution, Symbol, parse_any_sequence_variable, sym,
+    Substitution, Symbol, matches_head_constraint, parse_any_sequence_variable, sym,
 };
 
 /// Sequence variable elimination under a free head.
@@ -16,6 +16,9 @@ pub(crate) struct RuleSVEF {
     ground: Normal,
     variable: Option<Symbol>,
 
+    /// Optional head constraint which every element of the sequence must satisfy.
+    head: Option<Expr>,
+
     /// Have we produced the empty sequence as the first result yet?
     empty_produced: bool,
 
@@ -28,12 +31,14 @@ impl RuleSVEF {
         pattern: Normal,
         ground: Normal,
         variable: Option<Symbol>,
+        head: Option<Expr>,
         matches_empty: bool,
     ) -> Self {
         Self {
             pattern,
             ground,
             variable,
+            head,
             empty_produced: !matches_empty,
             ground_sequence: vec![],
         }
@@ -74,14 +79,13 @@ impl MatchRule for RuleSVEF {
         let g = match_equation.ground.try_normal()?;
 
         let p_elem0 = p.element(0)?;
-        let (matches_empty, variable, _) = parse_any_sequence_variable(p_elem0)?;
-
-        // TODO: Evaluate constraints for `BlankSequence[h]` and `Pattern[_, BlankSequence[h]]`.
+        let (matches_empty, variable, h) = parse_any_sequence_variable(p_elem0)?;
 
         Some(Self::new(
             p.clone(),
             g.clone(),
             variable.cloned(),
+            h.cloned(),
             matches_empty,
         ))
     }
@@ -108,6 +112,12 @@ impl Iterator for RuleSVEF {
 
         // Take the next term from the ground function.
         let next_element = self.ground.element(self.ground_sequence.len())?;
+
+        // Every longer sequence would also contain this element, so there are no further results.
+        if !matches_head_constraint(next_element, self.head.as_ref()) {
+            return None;
+        }
+
         self.ground_sequence.push(next_element.clone());
 
         // Construct the result.
@@ -2,7 +2,8 @@ use crate::{MatchEquation, MatchGenerator, MatchResultList, MatchRule};
 
 /// Trivial elimination.
 ///
-/// Rule for when `pattern` and `ground` match exactly.
+/// Rule for when `pattern` and `ground` match exactly. Atoms are compared by their kind as well as
+/// their value, so `1` does not match `1.0` and `"1"` does not match `1`.
 pub(crate) struct RuleT {
     match_equation: MatchEquation,
     exhausted: bool,
@@ -2,7 +2,7 @@ use crate::{
     MatchEquation, MatchGenerator, MatchResult, MatchResultList, MatchRule, Substitution,
     parse_individual_variable,
 };
-use crate::{Symbol, parse_any_sequence_variable, try_sequence};
+use crate::{Symbol, matches_head_constraint, parse_any_sequence_variable, try_sequence};
 
 /// Variable (both individual and sequence) elimination.
 ///
@@ -28,14 +28,16 @@ impl RuleVE {
 impl MatchRule for RuleVE {
     fn try_rule(match_equation: &MatchEquation) -> Option<Self> {
         // Match `x_` against any value.
-        if let Some((variable, _)) = parse_individual_variable(&match_equation.pattern) {
-            // TODO: Evaluate constraints for `Blank[h]` and `Pattern[_, Blank[h]]`.
+        if let Some((variable, h)) = parse_individual_variable(&match_equation.pattern) {
+            if !matches_head_constraint(&match_equation.ground, h) {
+                return None;
+            }
 
             return Some(Self::new(match_equation.clone(), variable.cloned()));
         }
 
         // Match `x__` and `x___` against sequence values.
-        if let Some((matches_empty, variable, _)) =
+        if let Some((matches_empty, variable, h)) =
             parse_any_sequence_variable(&match_equation.pattern)
         {
             let gelements = try_sequence(&match_equation.ground)?;
@@ -44,7 +46,12 @@ impl MatchRule for RuleVE {
                 return None;
             }
 
-            // TODO: Evaluate constraints for `BlankSequence[h]` and `Pattern[_, BlankSequence[h]]`.
+            if !gelements
+                .iter()
+                .all(|elem| matches_head_constraint(elem, h))
+            {
+                return None;
+            }
 
             return Some(Self::new(match_equation.clone(), variable.cloned()));
         }
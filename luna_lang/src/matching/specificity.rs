@@ -0,0 +1,151 @@
+use crate::{
+    Expr, parse_any_sequence_variable, parse_individual_variable, strip_hold_pattern, sym,
+};
+use std::cmp::Ordering;
+
+/// Compares how specific two patterns are, ordering the more specific pattern first. For example
+/// `f[0]` is more specific than `f[x_Integer]`, which in turn is more specific than `f[x_]` and then
+/// `f[x__]`. Patterns which cannot be told apart compare as equal, so that a stable sort leaves
+/// them in the order they were defined.
+pub fn compare_specificity(a: &Expr, b: &Expr) -> Ordering {
+    let (a, a_tested) = strip_test(a);
+    let (b, b_tested) = strip_test(b);
+
+    let ordering = generality(a).cmp(&generality(b));
+    if ordering != Ordering::Equal {
+        return ordering;
+    }
+
+    if let (Some(a_normal), Some(b_normal)) = (a.try_normal(), b.try_normal())
+        && generality(a) == Generality::Literal
+    {
+        let head = compare_specificity(a_normal.head(), b_normal.head());
+        if head != Ordering::Equal {
+            return head;
+        }
+
+        for (a_elem, b_elem) in a_normal.elements().iter().zip(b_normal.elements()) {
+            let elem = compare_specificity(a_elem, b_elem);
+            if elem != Ordering::Equal {
+                return elem;
+            }
+        }
+    }
+
+    // A pattern with a test or condition matches fewer expressions than the same pattern without.
+    b_tested.cmp(&a_tested)
+}
+
+/// How many expressions a part of a pattern can match, from the fewest to the most.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum Generality {
+    Literal,
+    HeadedBlank,
+    Blank,
+    HeadedBlankSequence,
+    BlankSequence,
+    HeadedBlankNullSequence,
+    BlankNullSequence,
+}
+
+fn generality(expr: &Expr) -> Generality {
+    if let Some((_, head)) = parse_individual_variable(expr) {
+        return match head {
+            Some(_) => Generality::HeadedBlank,
+            None => Generality::Blank,
+        };
+    }
+
+    if let Some((null, _, head)) = parse_any_sequence_variable(expr) {
+        return match (null, head) {
+            (false, Some(_)) => Generality::HeadedBlankSequence,
+            (false, None) => Generality::BlankSequence,
+            (true, Some(_)) => Generality::HeadedBlankNullSequence,
+            (true, None) => Generality::BlankNullSequence,
+        };
+    }
+
+    // `Except[c]` matches anything but `c`, so is as general as a blank.
+    if expr.is_normal_head(&sym!(Except)) {
+        return Generality::Blank;
+    }
+
+    Generality::Literal
+}
+
+/// Strips any `HoldPattern`, `PatternTest` and `Condition` wrappers from a pattern, returning the
+/// pattern being wrapped and whether it was tested.
+fn strip_test(expr: &Expr) -> (&Expr, bool) {
+    let mut expr = strip_hold_pattern(expr);
+    let mut tested = false;
+
+    while let Some(normal) = expr
+        .try_normal_head(&sym!(PatternTest))
+        .or_else(|| expr.try_normal_head(&sym!(Condition)))
+        && normal.len() == 2
+    {
+        expr = strip_hold_pattern(&normal.elements()[0]);
+        tested = true;
+    }
+
+    (expr, tested)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compare_specificity;
+    use crate::parse_str;
+    use std::cmp::Ordering;
+
+    fn assert_more_specific(a: &str, b: &str) {
+        let a = parse_str(a).unwrap();
+        let b = parse_str(b).unwrap();
+
+        assert_eq!(compare_specificity(&a, &b), Ordering::Less);
+        assert_eq!(compare_specificity(&b, &a), Ordering::Greater);
+    }
+
+    fn assert_equally_specific(a: &str, b: &str) {
+        let a = parse_str(a).unwrap();
+        let b = parse_str(b).unwrap();
+
+        assert_eq!(compare_specificity(&a, &b), Ordering::Equal);
+    }
+
+    #[test]
+    fn literals_before_blanks() {
+        assert_more_specific("f[0]", "f[x_]");
+        assert_more_specific("f[0]", "f[_Integer]");
+        assert_more_specific("f[g[x_]]", "f[x_]");
+    }
+
+    #[test]
+    fn blanks_by_generality() {
+        assert_more_specific("f[x_Integer]", "f[x_]");
+        assert_more_specific("f[x_]", "f[x__Integer]");
+        assert_more_specific("f[x__Integer]", "f[x__]");
+        assert_more_specific("f[x__]", "f[x___Integer]");
+        assert_more_specific("f[x___Integer]", "f[x___]");
+    }
+
+    #[test]
+    fn first_difference_decides() {
+        assert_more_specific("f[0, x_]", "f[x_, 0]");
+        assert_more_specific("f[x_, 0]", "f[x_, y_]");
+    }
+
+    #[test]
+    fn tests_and_conditions() {
+        assert_more_specific("f[x_?IntegerQ]", "f[x_]");
+        assert_more_specific("f[x_ /; x > 0]", "f[x_]");
+        assert_more_specific("f[0]", "f[x_?IntegerQ]");
+    }
+
+    #[test]
+    fn indistinguishable() {
+        assert_equally_specific("f[x_]", "f[y_]");
+        assert_equally_specific("f[0]", "f[1]");
+        assert_equally_specific("f[x_]", "g[x_, y_]");
+        assert_equally_specific("f[HoldPattern[x_]]", "f[x_]");
+    }
+}
@@ -0,0 +1,343 @@
+use super::{Associativity, FUNCTION_PRIORITY, INFIX_OPERATORS, UNARY_MINUS_PRIORITY};
+use crate::{Expr, ExprKind, Normal};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Displays an expression in infix form, the reverse of parsing, for example
+/// `Plus[a, Times[b, c]]` is displayed as `a + b*c`. Parentheses are only inserted where the
+/// priorities of the operators would otherwise group the expression differently when parsed.
+pub struct InfixForm<'a>(pub &'a Expr);
+
+impl Expr {
+    /// Renders the expression in infix form, see [`InfixForm`].
+    pub fn to_infix(&self) -> String {
+        InfixForm(self).to_string()
+    }
+}
+
+impl fmt::Display for InfixForm<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_expr(f, self.0, 0)
+    }
+}
+
+/// The priority of atoms and applications such as `f[x]`, which never need parentheses.
+const ATOM_PRIORITY: u8 = u8::MAX;
+
+/// Operators which are written without surrounding spaces, for example `b*c`.
+const TIGHT_OPERATORS: [&str; 4] = ["*", "/", "^", "?"];
+
+/// Operators which may have more than two elements, all of which are chained, for example
+/// `Plus[a, b, c]` is displayed as `a + b + c`.
+const CHAINED_OPERATORS: [&str; 6] = [
+    "Plus",
+    "Times",
+    "And",
+    "Or",
+    "StringJoin",
+    "CompoundExpression",
+];
+
+/// The forms an expression is displayed in.
+enum Form<'a> {
+    Atom,
+    Negation(&'a Expr),
+    Infix(&'static str, u8, Associativity),
+    Function(&'a Expr),
+    Postfix(&'a Expr, &'static str),
+    Slot(&'a Expr),
+    List,
+    Part,
+    Blank(&'static str, Option<&'a Expr>),
+    Pattern(&'a Expr, &'static str, Option<&'a Expr>),
+    Application,
+}
+
+fn form(expr: &Expr) -> Form<'_> {
+    let Some(normal) = expr.try_normal() else {
+        return Form::Atom;
+    };
+
+    let Some(head) = normal.head().try_symbol() else {
+        return Form::Application;
+    };
+
+    let elements = normal.elements();
+
+    if let ("Times", [minus_one, operand]) = (head.as_str(), elements)
+        && is_minus_one(minus_one)
+    {
+        return Form::Negation(operand);
+    }
+
+    if let Some((token, name, priority, associativity)) = INFIX_OPERATORS
+        .iter()
+        .find(|(_, name, _, _)| *name == head.as_str())
+        && (elements.len() == 2 || (elements.len() > 2 && CHAINED_OPERATORS.contains(name)))
+    {
+        return Form::Infix(token, *priority, *associativity);
+    }
+
+    match (head.as_str(), elements) {
+        ("Function", [body]) => Form::Function(body),
+        ("Factorial", [operand]) => Form::Postfix(operand, "!"),
+        ("Factorial2", [operand]) => Form::Postfix(operand, "!!"),
+        ("Unset", [operand]) => Form::Postfix(operand, " =."),
+        ("Slot", [index]) if index.try_integer().is_some_and(|index| *index >= 1) => {
+            Form::Slot(index)
+        }
+        ("List", _) => Form::List,
+        ("Part", [_, _, ..]) => Form::Part,
+        ("Pattern", [name, blank]) if name.try_symbol().is_some() => match form(blank) {
+            Form::Blank(underscores, blank_head) => Form::Pattern(name, underscores, blank_head),
+            _ => Form::Application,
+        },
+        (head, blank_head) => match (blank_underscores(head), blank_head) {
+            (Some(underscores), []) => Form::Blank(underscores, None),
+            (Some(underscores), [blank_head]) if blank_head.try_symbol().is_some() => {
+                Form::Blank(underscores, Some(blank_head))
+            }
+            _ => Form::Application,
+        },
+    }
+}
+
+fn priority(expr: &Expr) -> u8 {
+    match form(expr) {
+        // A rational such as `1/2` is displayed, and parsed back, as a division.
+        Form::Atom if expr.try_rational().is_some() => operator_priority("Divide"),
+        Form::Atom if is_negative(expr) => UNARY_MINUS_PRIORITY,
+        Form::Negation(_) => UNARY_MINUS_PRIORITY,
+        Form::Infix(_, priority, _) => priority,
+        Form::Function(_) => FUNCTION_PRIORITY,
+        _ => ATOM_PRIORITY,
+    }
+}
+
+/// Writes the expression, in parentheses if its priority is below the given minimum.
+fn write_expr(f: &mut Formatter<'_>, expr: &Expr, min_priority: u8) -> fmt::Result {
+    if priority(expr) < min_priority {
+        write!(f, "(")?;
+        write_unparenthesized(f, expr)?;
+        write!(f, ")")
+    } else {
+        write_unparenthesized(f, expr)
+    }
+}
+
+fn write_unparenthesized(f: &mut Formatter<'_>, expr: &Expr) -> fmt::Result {
+    match form(expr) {
+        Form::Atom => write!(f, "{}", expr),
+        Form::Negation(operand) => {
+            write!(f, "-")?;
+            write_expr(f, operand, UNARY_MINUS_PRIORITY + 1)
+        }
+        Form::Infix(token, priority, associativity) => {
+            let (left_priority, right_priority) = match associativity {
+                Associativity::Left => (priority, priority + 1),
+                Associativity::Right => (priority + 1, priority),
+            };
+
+            for (index, element) in elements(expr).iter().enumerate() {
+                if index > 0 {
+                    match token {
+                        ";" => write!(f, "; ")?,
+                        token if TIGHT_OPERATORS.contains(&token) => write!(f, "{}", token)?,
+                        token => write!(f, " {} ", token)?,
+                    }
+                }
+
+                let min_priority = if index == 0 {
+                    left_priority
+                } else {
+                    right_priority
+                };
+
+                write_expr(f, element, min_priority)?;
+            }
+
+            Ok(())
+        }
+        Form::Function(body) => {
+            write_expr(f, body, FUNCTION_PRIORITY + 1)?;
+            write!(f, " &")
+        }
+        Form::Postfix(operand, token) => {
+            write_expr(f, operand, ATOM_PRIORITY)?;
+            write!(f, "{}", token)
+        }
+        Form::Slot(index) if index.try_integer().is_some_and(|index| *index == 1) => {
+            write!(f, "#")
+        }
+        Form::Slot(index) => write!(f, "#{}", index),
+        Form::List => {
+            write!(f, "{{")?;
+            write_elements(f, elements(expr))?;
+            write!(f, "}}")
+        }
+        Form::Part => {
+            let (value, indices) = elements(expr).split_first().unwrap();
+
+            write_expr(f, value, ATOM_PRIORITY)?;
+            write!(f, "[[")?;
+            write_elements(f, indices)?;
+            write!(f, "]]")
+        }
+        Form::Blank(underscores, blank_head) => write_blank(f, underscores, blank_head),
+        Form::Pattern(name, underscores, blank_head) => {
+            write!(f, "{}", name)?;
+            write_blank(f, underscores, blank_head)
+        }
+        Form::Application => {
+            let normal = expr.try_normal().unwrap();
+
+            write_expr(f, normal.head(), ATOM_PRIORITY)?;
+            write!(f, "[")?;
+            write_elements(f, normal.elements())?;
+            write!(f, "]")
+        }
+    }
+}
+
+fn write_elements(f: &mut Formatter<'_>, elements: &[Expr]) -> fmt::Result {
+    for (index, element) in elements.iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+
+        write_expr(f, element, 0)?;
+    }
+
+    Ok(())
+}
+
+fn write_blank(f: &mut Formatter<'_>, underscores: &str, blank_head: Option<&Expr>) -> fmt::Result {
+    match blank_head {
+        Some(blank_head) => write!(f, "{}{}", underscores, blank_head),
+        None => write!(f, "{}", underscores),
+    }
+}
+
+fn operator_priority(name: &str) -> u8 {
+    INFIX_OPERATORS
+        .iter()
+        .find(|operator| operator.1 == name)
+        .map_or(ATOM_PRIORITY, |operator| operator.2)
+}
+
+fn elements(expr: &Expr) -> &[Expr] {
+    expr.try_normal().map(Normal::elements).unwrap_or_default()
+}
+
+fn blank_underscores(head: &str) -> Option<&'static str> {
+    match head {
+        "Blank" => Some("_"),
+        "BlankSequence" => Some("__"),
+        "BlankNullSequence" => Some("___"),
+        _ => None,
+    }
+}
+
+fn is_minus_one(expr: &Expr) -> bool {
+    expr.try_integer().is_some_and(|value| *value == -1)
+}
+
+fn is_negative(expr: &Expr) -> bool {
+    match expr.kind() {
+        ExprKind::Integer(value) => value.cmp0() == Ordering::Less,
+        ExprKind::Rational(value) => value.cmp0() == Ordering::Less,
+        ExprKind::Real(value) => value.as_float().is_sign_negative(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigRational, Expr, Normal, Symbol, parse_str};
+
+    fn displays_as(input: &str, expected: &str) {
+        let expr = parse_str(input).unwrap();
+
+        assert_eq!(expr.to_infix(), expected);
+        assert_eq!(parse_str(expected).unwrap(), expr);
+    }
+
+    #[test]
+    fn atoms() {
+        displays_as("x", "x");
+        displays_as("42", "42");
+        displays_as("-42", "-42");
+        displays_as("\"a\\nb\"", "\"a\\nb\"");
+    }
+
+    #[test]
+    fn infix_operators() {
+        displays_as("Plus[a, Times[b, c]]", "a + b*c");
+        displays_as("Times[Plus[a, b], c]", "(a + b)*c");
+        displays_as("Rule[x, Power[y, 2]]", "x -> y^2");
+        displays_as("SetDelayed[f[x_], x]", "f[x_] := x");
+    }
+
+    #[test]
+    fn rationals() {
+        let half = Expr::from(BigRational::from((1, 2)));
+        let expr = Expr::from(Normal::new(Symbol::new("Power"), vec![half.clone(), half]));
+
+        assert_eq!(expr.to_infix(), "(1/2)^(1/2)");
+    }
+
+    #[test]
+    fn chained_operators() {
+        let expr = parse_str("Plus[a, b, c]").unwrap();
+
+        assert_eq!(expr.to_infix(), "a + b + c");
+    }
+
+    #[test]
+    fn associativity() {
+        displays_as("a - b - c", "a - b - c");
+        displays_as("Subtract[a, Subtract[b, c]]", "a - (b - c)");
+        displays_as("2^3^2", "2^3^2");
+        displays_as("Power[Power[2, 3], 2]", "(2^3)^2");
+    }
+
+    #[test]
+    fn negation() {
+        displays_as("-a", "-a");
+        displays_as("-a^2", "-a^2");
+        displays_as("Power[-a, 2]", "(-a)^2");
+        displays_as("Power[-2, 2]", "(-2)^2");
+        displays_as("a + -b", "a + -b");
+    }
+
+    #[test]
+    fn functions() {
+        displays_as("# + 1 &", "# + 1 &");
+        displays_as("(#1 + #2 &)[1, 2]", "(# + #2 &)[1, 2]");
+        displays_as("f = # &", "f = # &");
+    }
+
+    #[test]
+    fn patterns() {
+        displays_as("f[x_, y__Integer, ___]", "f[x_, y__Integer, ___]");
+        displays_as("x_?IntegerQ", "x_?IntegerQ");
+    }
+
+    #[test]
+    fn lists_and_parts() {
+        displays_as("{1, a + b, {c}}", "{1, a + b, {c}}");
+        displays_as("x[[1, 2]]", "x[[1, 2]]");
+    }
+
+    #[test]
+    fn postfix_operators() {
+        displays_as("Factorial[n + 1]", "(n + 1)!");
+        displays_as("5!!", "5!!");
+    }
+
+    #[test]
+    fn compound_expression() {
+        displays_as("a = 1; b", "a = 1; b");
+    }
+}
@@ -1,24 +1,99 @@
-use crate::Symbol;
+mod infix_form;
+
+pub use infix_form::*;
+
+use crate::{Context, Symbol};
 use crate::{BigFloat, Normal};
-use crate::{BigInteger, Expr};
+use crate::{BigInteger, BigRational, Expr, ExprKind, MAX_PRECISION_DIGITS, digits_to_precision};
 
 use nom::{
     IResult, Parser,
     branch::alt,
-    bytes::complete::{escaped_transform, tag, take_until, take_while1},
-    character::complete::{char, digit1, multispace0, one_of},
-    combinator::{cut, map, opt, peek, recognize},
-    error::ParseError,
-    multi::{many0, many1, separated_list0},
+    bytes::complete::{escaped_transform, tag, take_until, take_while_m_n, take_while1},
+    character::complete::{alphanumeric1, char, digit1, multispace0, one_of},
+    combinator::{cut, map, map_opt, map_res, not, opt, peek, recognize, success, value},
+    error::{ErrorKind, ParseError},
+    multi::{many0, many1, separated_list0, separated_list1},
     number::complete::recognize_float,
     sequence::{delimited, pair, preceded, terminated},
 };
 use nom::combinator::eof;
 use rug::ops::CompleteRound;
+use std::cell::Cell;
 
-// TODO: This should probably exist on the context?
+/// The precision, in bits, given to reals parsed without a context. This matches machine precision.
 pub const DEFAULT_REAL_PRECISION: u32 = 53;
 
+/// The maximum depth operands may be nested within one another, for example by parentheses or
+/// the arguments of an application. Each level of nesting recurses through the parser, and a debug
+/// build overflows a thread with the default stack size of 2 MiB at around 100 levels, so this is
+/// far lower than the depth of expressions that can be evaluated. Input nested any deeper fails to
+/// parse with an error giving this limit.
+pub const MAX_NESTING_DEPTH: usize = 64;
+
+thread_local! {
+    /// The depth of the operand currently being parsed, see [`MAX_NESTING_DEPTH`].
+    static NESTING_DEPTH: Cell<usize> = const { Cell::new(0) };
+
+    /// Whether the current parse has failed by reaching [`MAX_NESTING_DEPTH`].
+    static NESTING_LIMIT_REACHED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Infix operators with a priority above this are captured by a trailing `&`, for example
+/// `# + 1 &` is `Function[# + 1]` whereas `f = # &` is `f = Function[#]`.
+const FUNCTION_PRIORITY: u8 = 12;
+
+/// How a chain of infix operators of equal priority is grouped.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Associativity {
+    /// `a / b / c` is `Divide[Divide[a, b], c]`.
+    Left,
+
+    /// `a ^ b ^ c` is `Power[a, Power[b, c]]`.
+    Right,
+}
+
+/// The infix operators, as their token, the head they produce, their priority and their
+/// associativity. Tokens sharing a prefix are ordered longest first, for example `@@@` before `@@`
+/// and `===` before `==`.
+pub(crate) const INFIX_OPERATORS: [(&str, &str, u8, Associativity); 31] = [
+    ("@@@", "MapApply", 120, Associativity::Left),
+    ("=!=", "UnsameQ", 20, Associativity::Left),
+    ("===", "SameQ", 20, Associativity::Left),
+    ("//.", "ReplaceRepeated", 13, Associativity::Left),
+    ("@@", "Apply", 120, Associativity::Left),
+    ("/@", "Map", 120, Associativity::Left),
+    ("/.", "ReplaceAll", 13, Associativity::Left),
+    ("/;", "Condition", 16, Associativity::Left),
+    ("/:", "TagSet", 12, Associativity::Right),
+    ("//", "PostfixApplication", 10, Associativity::Left),
+    ("<>", "StringJoin", 90, Associativity::Left),
+    ("<=", "LessEqual", 26, Associativity::Left),
+    (":>", "RuleDelayed", 15, Associativity::Right),
+    (":=", "SetDelayed", 12, Associativity::Right),
+    (">=", "GreaterEqual", 25, Associativity::Left),
+    ("->", "Rule", 15, Associativity::Right),
+    ("==", "Equal", 21, Associativity::Left),
+    ("!=", "Unequal", 21, Associativity::Left),
+    (";;", "Span", 80, Associativity::Left),
+    ("&&", "And", 4, Associativity::Left),
+    ("||", "Or", 3, Associativity::Left),
+    ("<", "Less", 26, Associativity::Left),
+    (">", "Greater", 25, Associativity::Left),
+    ("=", "Set", 12, Associativity::Right),
+    ("+", "Plus", 60, Associativity::Left),
+    ("-", "Subtract", 60, Associativity::Left),
+    ("*", "Times", 100, Associativity::Left),
+    ("/", "Divide", 100, Associativity::Left),
+    ("^", "Power", 110, Associativity::Right),
+    (";", "CompoundExpression", 2, Associativity::Left),
+    ("?", "PatternTest", 130, Associativity::Left),
+];
+
+/// The operand of a unary minus captures the infix operators with a priority above this, for
+/// example `-a^2` is `Times[-1, Power[a, 2]]` whereas `-a + b` is `Plus[Times[-1, a], b]`.
+const UNARY_MINUS_PRIORITY: u8 = 100;
+
 #[macro_export]
 macro_rules! parse {
     ($s:expr) => {
@@ -27,16 +102,33 @@ macro_rules! parse {
 }
 
 pub fn parse_str(expr: &str) -> Result<Expr, String> {
-    match parse_root(expr) {
+    parse_str_with_precision(expr, DEFAULT_REAL_PRECISION)
+}
+
+/// Parses an expression, giving reals the default precision configured on the context and
+/// resolving symbols against its `$Context` and `$ContextPath`.
+pub fn parse_str_with_context(expr: &str, context: &Context) -> Result<Expr, String> {
+    parse_str_with_precision(expr, context.default_real_precision())
+        .map(|expr| context.resolve_symbols(&expr))
+}
+
+fn parse_str_with_precision(expr: &str, precision: u32) -> Result<Expr, String> {
+    NESTING_LIMIT_REACHED.set(false);
+
+    match parse_root(expr, precision) {
+        Err(_) if NESTING_LIMIT_REACHED.get() => Err(format!(
+            "Error while parsing: expressions may be nested at most {} levels deep",
+            MAX_NESTING_DEPTH
+        )),
         Err(error) => Err(format!("Error while parsing: {}", error)),
         Ok((_, result)) => Ok(result),
     }
 }
 
-fn parse_root(i: &str) -> IResult<&str, Expr> {
+fn parse_root(i: &str, precision: u32) -> IResult<&str, Expr> {
     let (i, _) = many0(parse_comment).parse(i)?;
     let (i, _) = multispace0(i)?;
-    let (i, expr) = signed_expr.parse(i)?;
+    let (i, expr) = signed_expr(i, precision)?;
     let (i, _) = multispace0(i)?;
     let (i, _) = eof(i)?;
 
@@ -47,61 +139,212 @@ fn parse_comment(i: &str) -> IResult<&str, &str> {
     delimited(tag("(*"), take_until("*)"), tag("*)")).parse(i)
 }
 
-fn signed_expr(i: &str) -> IResult<&str, Expr> {
-    let (i, first_head) = expr(i)?;
-    let (i, mut list_infixes) = many0(pair(parse_infix_operator, expr)).parse(i)?;
+fn signed_expr(i: &str, precision: u32) -> IResult<&str, Expr> {
+    let (mut i, first_head) = operand(i, precision)?;
+    let mut list_infixes = vec![((Symbol::new(""), u8::MAX, Associativity::Left), first_head)];
+
+    loop {
+        // A trailing `&` turns the tightly bound operands which precede it into a pure function.
+        if let Ok((rest, _)) = parse_function_marker(i) {
+            let start = list_infixes
+                .iter()
+                .rposition(|((_, priority, _), _)| *priority <= FUNCTION_PRIORITY)
+                .unwrap_or(0);
+
+            let mut captured = list_infixes.split_off(start);
+            let (operator, first) = captured.remove(0);
+            captured.insert(0, ((Symbol::new(""), u8::MAX, Associativity::Left), first));
+
+            let function = Expr::from(Normal::new(
+                Symbol::new("Function"),
+                vec![fold_infixes(captured)],
+            ));
+
+            list_infixes.push((operator, function));
+            i = rest;
+            continue;
+        }
+
+        match pair(parse_infix_operator, |i| operand(i, precision)).parse(i) {
+            Ok((rest, infix)) => {
+                list_infixes.push(infix);
+                i = rest;
+            }
+            Err(nom::Err::Error(_)) => {
+                // A trailing `;` is followed by an implicit `Null`, for example `a = 1;`.
+                match parse_infix_operator(i) {
+                    Ok((rest, operator)) if operator.0 == Symbol::new("CompoundExpression") => {
+                        list_infixes.push((operator, Expr::from(Symbol::new("Null"))));
+                        i = rest;
+                    }
+                    _ => break,
+                }
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok((i, fold_infixes(list_infixes)))
+}
+
+/// Parses the operand of an infix operator, which may be negated by a unary minus. Fails if the
+/// operand is nested deeper than [`MAX_NESTING_DEPTH`].
+fn operand(i: &str, precision: u32) -> IResult<&str, Expr> {
+    let depth = NESTING_DEPTH.get();
+    if depth >= MAX_NESTING_DEPTH {
+        NESTING_LIMIT_REACHED.set(true);
+        return Err(nom::Err::Failure(ParseError::from_error_kind(i, ErrorKind::TooLarge)));
+    }
+
+    NESTING_DEPTH.set(depth + 1);
+    let result = nested_operand(i, precision);
+    NESTING_DEPTH.set(depth);
+
+    result
+}
+
+fn nested_operand(i: &str, precision: u32) -> IResult<&str, Expr> {
+    let (i, _) = multispace0(i)?;
+    let (mut i, minus) = opt(char('-')).parse(i)?;
 
-    list_infixes.insert(0, ((Symbol::new(""), u8::MAX), first_head));
+    if minus.is_none() {
+        return expr(i, precision);
+    }
 
+    let (rest, first) = operand(i, precision)?;
+    let mut list_infixes = vec![((Symbol::new(""), u8::MAX, Associativity::Left), first)];
+    i = rest;
+
+    loop {
+        match pair(parse_infix_operator, |i| operand(i, precision)).parse(i) {
+            Ok((rest, infix)) if infix.0.1 > UNARY_MINUS_PRIORITY => {
+                list_infixes.push(infix);
+                i = rest;
+            }
+            Ok(_) | Err(nom::Err::Error(_)) => break,
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok((i, negate(fold_infixes(list_infixes))))
+}
+
+/// Negates an expression, folding the sign into number literals so that `-2` is the integer `-2`
+/// rather than `Times[-1, 2]`.
+fn negate(expr: Expr) -> Expr {
+    match expr.kind() {
+        ExprKind::Integer(value) => Expr::from(-value.clone()),
+        ExprKind::Rational(value) => Expr::from(-value.clone()),
+        ExprKind::Real(value) => Expr::from(-value.as_float().clone()),
+        _ => Expr::from(Normal::new(
+            Symbol::new("Times"),
+            vec![Expr::from(BigInteger::NEG_ONE.clone()), expr],
+        )),
+    }
+}
+
+fn parse_function_marker(i: &str) -> IResult<&str, char> {
+    let (i, _) = multispace0(i)?;
+    let (i, marker) = terminated(char('&'), not(char('&'))).parse(i)?;
+    let (i, _) = multispace0(i)?;
+
+    Ok((i, marker))
+}
+
+/// Folds a list of operands, each preceded by an infix operator, its priority and associativity,
+/// into a single expression. The first operator is ignored. Chains of operators of equal priority
+/// are grouped by their associativity, for example `a - b + c` is `Plus[Subtract[a, b], c]`
+/// whereas `a ^ b ^ c` is `Power[a, Power[b, c]]`.
+fn fold_infixes(mut list_infixes: Vec<((Symbol, u8, Associativity), Expr)>) -> Expr {
     while list_infixes.len() > 1 {
         let mut max_priority = 0;
         let mut max_priority_position = 1;
 
-        for (x, ((_, priority), _)) in list_infixes.iter().skip(1).enumerate() {
-            if max_priority < *priority {
+        for (x, ((_, priority, associativity), _)) in list_infixes.iter().skip(1).enumerate() {
+            if max_priority < *priority
+                || (max_priority == *priority && *associativity == Associativity::Right)
+            {
                 max_priority = *priority;
                 max_priority_position = x + 1;
             }
         }
 
-        let ((infix_operator, _), post_infix) = list_infixes.remove(max_priority_position);
-        let ((previous_infix_operator, previous_priority), new_child) =
-            list_infixes.remove(max_priority_position - 1);
+        let ((infix_operator, _, _), post_infix) = list_infixes.remove(max_priority_position);
+        let (previous_infix_operator, new_child) = list_infixes.remove(max_priority_position - 1);
 
-        let new_head = Expr::from(Normal::new(infix_operator, vec![new_child, post_infix]));
+        let new_head = apply_infix(infix_operator, new_child, post_infix);
 
-        list_infixes.insert(
-            max_priority_position - 1,
-            ((previous_infix_operator, previous_priority), new_head),
-        );
+        list_infixes.insert(max_priority_position - 1, (previous_infix_operator, new_head));
     }
 
-    let ((_, _), final_head) = &list_infixes[0];
+    let (_, final_head) = list_infixes.swap_remove(0);
+
+    final_head
+}
+
+/// Applies an infix operator to its operands. The `/:` operator prefixes an assignment, so that
+/// `tag /: lhs = rhs`, which groups as `TagSet[tag, Set[lhs, rhs]]`, becomes
+/// `TagSet[tag, lhs, rhs]`, and `tag /: lhs := rhs` becomes `TagSetDelayed[tag, lhs, rhs]`.
+fn apply_infix(operator: Symbol, left: Expr, right: Expr) -> Expr {
+    if operator == Symbol::new("TagSet")
+        && let Some(assignment) = right.try_normal()
+        && let [lhs, rhs] = assignment.elements()
+    {
+        let head = match assignment.head().try_symbol().map(Symbol::as_str) {
+            Some("Set") => Some("TagSet"),
+            Some("SetDelayed") => Some("TagSetDelayed"),
+            _ => None,
+        };
+
+        if let Some(head) = head {
+            return Expr::from(Normal::new(
+                Symbol::new(head),
+                vec![left, lhs.clone(), rhs.clone()],
+            ));
+        }
+    }
 
-    Ok((i, final_head.clone()))
+    Expr::from(Normal::new(operator, vec![left, right]))
 }
 
-fn expr(i: &str) -> IResult<&str, Expr> {
+fn expr(i: &str, precision: u32) -> IResult<&str, Expr> {
     let (i, _) = multispace0(i)?;
     let (i, _) = many0(parse_comment).parse(i)?;
     let (i, _) = multispace0(i)?;
 
     let (i, mut new_head) = alt((
         parse_slot,
-        parse_array,
-        parse_parenthesized,
-        parse_part,
-        parse_function,
-        parse_num,
+        |i| parse_array(i, precision),
+        |i| parse_parenthesized(i, precision),
+        |i| parse_part(i, precision),
+        |i| parse_function(i, precision),
+        |i| parse_num(i, precision),
         parse_pattern,
         parse_symbol,
         parse_string,
-        parse_association,
+        |i| parse_association(i, precision),
     ))
     .parse(i)?;
 
+    // Any expression can be applied to arguments, for example `(# + 1 &)[5]`.
+    let (i, exprs) = many0(|i| parse_arguments(i, precision)).parse(i)?;
+    for elems in exprs {
+        new_head = Expr::from(Normal::new(new_head, elems));
+    }
+
+    // `x =.` unsets the value of `x`. A following digit is a real, for example `x =.5`.
+    let (i, unset) = opt(preceded(
+        multispace0,
+        terminated(tag("=."), not(one_of("0123456789"))),
+    ))
+    .parse(i)?;
+
+    if unset.is_some() {
+        new_head = Expr::from(Normal::new(Symbol::new("Unset"), vec![new_head]));
+    }
+
     let (i, _) = multispace0(i)?;
-    let (i, children_from_at_sign) = opt(preceded(char('@'), expr)).parse(i)?;
+    let (i, children_from_at_sign) = opt(preceded(char('@'), |i| expr(i, precision))).parse(i)?;
 
     if let Some(child) = children_from_at_sign {
         return Ok((i, Expr::from(Normal::new(new_head, vec![child]))));
@@ -109,20 +352,21 @@ fn expr(i: &str) -> IResult<&str, Expr> {
 
     let (i, part) = opt(delimited(
         preceded(multispace0, tag("[[")),
-        expr,
+        separated_list1(preceded(multispace0, char(',')), |i| signed_expr(i, precision)),
         preceded(multispace0, tag("]]")),
     ))
     .parse(i)?;
 
-    if let Some(p) = part {
-        return Ok((
-            i,
-            Expr::from(Normal::new(Symbol::new("Part"), vec![new_head, p])),
-        ));
+    if let Some(mut indices) = part {
+        let mut elems = vec![new_head];
+        elems.append(&mut indices);
+
+        return Ok((i, Expr::from(Normal::new(Symbol::new("Part"), elems))));
     }
 
     // Handle postfix operators: !, !!, '
-    // These can be chained, e.g., 5!! or f''
+    // These can be chained, e.g., 5!! or f'', where each ' applies `Derivative[1]` once, so `f''`
+    // is `Derivative[1][Derivative[1][f]]`.
     let (i, postfix_ops) = many0(parse_single_postfix_op).parse(i)?;
     for op in postfix_ops {
         match op {
@@ -134,14 +378,11 @@ fn expr(i: &str) -> IResult<&str, Expr> {
             }
             "'" => {
                 new_head = Expr::from(Normal::new(
-                    Symbol::new("Lookup"),
-                    vec![
-                        Expr::from(Normal::new(
-                            Symbol::new("Derivative"),
-                            vec![Expr::from(BigInteger::ONE.clone())],
-                        )),
-                        new_head,
-                    ],
+                    Expr::from(Normal::new(
+                        Symbol::new("Derivative"),
+                        vec![Expr::from(BigInteger::ONE.clone())],
+                    )),
+                    vec![new_head],
                 ));
             }
             _ => {}
@@ -152,18 +393,36 @@ fn expr(i: &str) -> IResult<&str, Expr> {
     let (i, _) = many0(parse_comment).parse(i)?;
     let (i, _) = multispace0(i)?;
 
-    let (i, lambda) = opt(char('&')).parse(i)?;
+    Ok((i, new_head))
+}
 
-    if lambda.is_some() {
-        let (i, _) = multispace0(i)?;
+/// Parses the comma separated elements of a list, association or application. A trailing comma is
+/// allowed after the last element, so `{a, b,}` has the same elements as `{a, b}`.
+fn parse_elements(i: &str, precision: u32) -> IResult<&str, Vec<Expr>> {
+    let (i, exprs) =
+        separated_list0(preceded(multispace0, char(',')), |i| signed_expr(i, precision))
+            .parse(i)?;
 
-        Ok((
-            i,
-            Expr::from(Normal::new(Symbol::new("Function"), vec![new_head])),
-        ))
-    } else {
-        Ok((i, new_head))
+    if exprs.is_empty() {
+        return Ok((i, exprs));
     }
+
+    let (i, _) = opt(preceded(multispace0, char(','))).parse(i)?;
+
+    Ok((i, exprs))
+}
+
+/// Parses the arguments of an application such as `[a, b]`, without consuming the `[[` of a
+/// `Part` expression.
+fn parse_arguments(i: &str, precision: u32) -> IResult<&str, Vec<Expr>> {
+    preceded(
+        terminated(char('['), not(char('['))),
+        cut(terminated(
+            |i| parse_elements(i, precision),
+            preceded(multispace0, char(']')),
+        )),
+    )
+    .parse(i)
 }
 
 fn parse_slot(i: &str) -> IResult<&str, Expr> {
@@ -171,50 +430,193 @@ fn parse_slot(i: &str) -> IResult<&str, Expr> {
     let (i, opt_slot_num) = opt(digit1).parse(i)?;
 
     let slot_num = match opt_slot_num {
-        None => 1,
-        Some(num) => num.parse::<i32>().unwrap(),
+        None => BigInteger::ONE.clone(),
+        Some(num) => BigInteger::from_str_radix(num, 10).unwrap(),
     };
 
     Ok((
         i,
-        Expr::from(Normal::new(
-            Symbol::new("Slot"),
-            vec![Expr::from(BigInteger::from(slot_num))],
-        )),
+        Expr::from(Normal::new(Symbol::new("Slot"), vec![Expr::from(slot_num)])),
     ))
 }
 
-fn parse_num(i: &str) -> IResult<&str, Expr> {
+fn parse_num(i: &str, precision: u32) -> IResult<&str, Expr> {
     let (i, potential_sign) = opt(tag("-")).parse(i)?;
     let (_, potential_num) = peek(recognize_float).parse(i)?;
 
     let sign = if potential_sign.is_some() { -1 } else { 1 };
+    let is_real = potential_num.contains(['.', 'e', 'E']);
 
-    if potential_num.contains('.') {
-        map(recognize_float, |r| {
-            Expr::from(BigFloat::parse(r).unwrap().complete(DEFAULT_REAL_PRECISION))
-        })
-        .parse(i)
-    } else {
-        map(digit1, |r| {
-            Expr::from(BigInteger::from_str_radix(r, 10).unwrap() * sign)
-        })
-        .parse(i)
+    let (i, num) = if is_real { recognize_float(i)? } else { digit1(i)? };
+
+    if !is_real && let (i, Some(value)) = opt(|i| parse_base_digits(i, num)).parse(i)? {
+        return Ok((i, Expr::from(value * sign)));
     }
+
+    let (i, annotation) = opt(parse_precision_annotation).parse(i)?;
+    let (i, exponent) = opt(parse_exponent).parse(i)?;
+
+    let expr = match (annotation, exponent) {
+        (Some(annotation), _) => {
+            let num = with_exponent(num, exponent);
+            let Some(precision) = annotation.precision_of(&num) else {
+                return Err(nom::Err::Failure(ParseError::from_error_kind(i, ErrorKind::TooLarge)));
+            };
+
+            Expr::from(BigFloat::parse(num).unwrap().complete(precision) * sign)
+        }
+        (None, _) if is_real => {
+            let num = with_exponent(num, exponent);
+            Expr::from(BigFloat::parse(num).unwrap().complete(precision) * sign)
+        }
+        // An exact number has as many digits as its exponent, which is capped in the same way as
+        // the digits of precision of a real.
+        (None, Some(exponent)) if exponent.unsigned_abs() > MAX_PRECISION_DIGITS => {
+            return Err(nom::Err::Failure(ParseError::from_error_kind(i, ErrorKind::TooLarge)));
+        }
+        (None, Some(exponent)) => {
+            let value = BigInteger::from_str_radix(num, 10).unwrap() * sign;
+            let scale = BigInteger::from(BigInteger::u_pow_u(10, exponent.unsigned_abs()));
+
+            if exponent < 0 {
+                Expr::from(BigRational::from((value, scale)))
+            } else {
+                Expr::from(value * scale)
+            }
+        }
+        (None, None) => Expr::from(BigInteger::from_str_radix(num, 10).unwrap() * sign),
+    };
+
+    Ok((i, expr))
+}
+
+/// Parses the digits of a base-n integer, for example the `^^FF` of `16^^FF`. Once the `^^` has
+/// been seen, an out of range base or an invalid digit is a parse failure.
+fn parse_base_digits<'a>(i: &'a str, base: &str) -> IResult<&'a str, BigInteger> {
+    let (i, _) = tag("^^")(i)?;
+
+    cut(map_res(alphanumeric1, |digits: &str| {
+        let radix = base
+            .parse::<i32>()
+            .ok()
+            .filter(|radix| (2..=36).contains(radix))
+            .ok_or_else(|| format!("Invalid base {}", base))?;
+
+        BigInteger::from_str_radix(digits, radix).map_err(|error| error.to_string())
+    }))
+    .parse(i)
+}
+
+/// Parses a `*^` exponent, for example the `*^3` of `2.5*^3`, meaning `2.5 * 10^3`.
+fn parse_exponent(i: &str) -> IResult<&str, i32> {
+    preceded(
+        tag("*^"),
+        map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse),
+    )
+    .parse(i)
 }
 
-fn unescape_string(i: &str) -> IResult<&str, &str> {
-    let (i, str) = alt((tag("\\"), tag("\""))).parse(i)?;
-    match str {
-        "\\" => Ok((i, "\\")),
-        "\"" => Ok((i, "\"")),
-        _ => Ok((i, "")),
+/// Appends a `*^` exponent to the textual representation of a real, as an `e` exponent.
+fn with_exponent(num: &str, exponent: Option<i32>) -> String {
+    match exponent {
+        // A number such as `1e5*^2` already has an exponent, so it is combined with the new one.
+        Some(exponent) if num.contains(['e', 'E']) => {
+            let (mantissa, existing) = num.split_once(['e', 'E']).unwrap();
+
+            match existing.parse::<i64>() {
+                Ok(existing) => format!("{}e{}", mantissa, existing.saturating_add(exponent as i64)),
+                // An exponent too large for an `i64` overflows, or underflows, whatever is added.
+                Err(_) => num.to_string(),
+            }
+        }
+        Some(exponent) => format!("{}e{}", num, exponent),
+        None => num.to_string(),
     }
 }
 
+/// A precision annotation following a number, for example the `` `20 `` of `` 3.14`20 ``.
+#[derive(Clone, Copy)]
+enum PrecisionAnnotation {
+    /// A bare `` ` ``, meaning machine precision.
+    Machine,
+
+    /// `` `p ``, meaning `p` significant decimal digits.
+    Precision(u32),
+
+    /// ``` ``a ```, meaning `a` decimal digits after the decimal point.
+    Accuracy(u32),
+}
+
+impl PrecisionAnnotation {
+    /// Returns the number of bits of precision the annotation gives to the number, or `None` if
+    /// this is more than [`MAX_PRECISION_DIGITS`] allows.
+    fn precision_of(&self, num: &str) -> Option<u32> {
+        let max_precision = digits_to_precision(MAX_PRECISION_DIGITS);
+
+        match self {
+            PrecisionAnnotation::Machine => Some(DEFAULT_REAL_PRECISION),
+            PrecisionAnnotation::Precision(digits) => (*digits <= MAX_PRECISION_DIGITS)
+                .then(|| digits_to_precision(*digits).max(1)),
+            PrecisionAnnotation::Accuracy(digits) => {
+                if *digits > MAX_PRECISION_DIGITS {
+                    return None;
+                }
+
+                // The significant digits are the digits after the decimal point plus those before
+                // it, which is approximated here by the binary exponent of the number.
+                let exponent = BigFloat::parse(num)
+                    .map(|value| value.complete(DEFAULT_REAL_PRECISION).get_exp().unwrap_or(0))
+                    .unwrap_or(0);
+
+                let precision = (digits_to_precision(*digits) as i64 + exponent as i64).max(1);
+
+                u32::try_from(precision).ok().filter(|precision| *precision <= max_precision)
+            }
+        }
+    }
+}
+
+fn parse_precision_annotation(i: &str) -> IResult<&str, PrecisionAnnotation> {
+    let (i, _) = char('`')(i)?;
+
+    alt((
+        map(preceded(char('`'), map_res(digit1, str::parse)), PrecisionAnnotation::Accuracy),
+        map(map_res(digit1, str::parse), PrecisionAnnotation::Precision),
+        success(PrecisionAnnotation::Machine),
+    ))
+    .parse(i)
+}
+
+/// Decodes the escape sequence following a `\` within a string, for example `\n` or the unicode
+/// escape `\:00e9`.
+fn unescape_string(i: &str) -> IResult<&str, char> {
+    alt((
+        value('\\', char('\\')),
+        value('"', char('"')),
+        value('\n', char('n')),
+        value('\t', char('t')),
+        value('\r', char('r')),
+        preceded(
+            char(':'),
+            map_opt(take_while_m_n(4, 4, |c: char| c.is_ascii_hexdigit()), |hex| {
+                u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+            }),
+        ),
+    ))
+    .parse(i)
+}
+
 fn parse_pattern(i: &str) -> IResult<&str, Expr> {
     let (i, potential_name) = opt(recognize(parse_symbol)).parse(i)?;
-    let (i, underscores) = take_while1(|c| c == '_').parse(i)?;
+    let (rest, underscores) = take_while1(|c| c == '_').parse(i)?;
+
+    // There is no blank with more than three underscores, so `x____` is rejected outright rather
+    // than being parsed as something else.
+    if underscores.len() > 3 {
+        return Err(nom::Err::Failure(ParseError::from_error_kind(i, ErrorKind::TooLarge)));
+    }
+
+    let i = rest;
     let (i, potential_head) = opt(recognize(parse_symbol)).parse(i)?;
 
     let pattern_head = match potential_head {
@@ -225,11 +627,7 @@ fn parse_pattern(i: &str) -> IResult<&str, Expr> {
     let pattern = match underscores.len() {
         1 => Expr::from(Normal::new(Symbol::new("Blank"), pattern_head)),
         2 => Expr::from(Normal::new(Symbol::new("BlankSequence"), pattern_head)),
-        3 => Expr::from(Normal::new(Symbol::new("BlankNullSequence"), pattern_head)),
-        _ => {
-            // More than 3 underscores is invalid, but we'll treat as ZeroOrMore
-            Expr::from(Normal::new(Symbol::new("BlankNullSequence"), pattern_head))
-        }
+        _ => Expr::from(Normal::new(Symbol::new("BlankNullSequence"), pattern_head)),
     };
 
     Ok(match potential_name {
@@ -268,72 +666,37 @@ fn parse_string(i: &str) -> IResult<&str, Expr> {
 }
 
 fn parse_symbol<'a, E: ParseError<&'a str>>(i: &'a str) -> IResult<&'a str, Expr, E> {
+    let name = || take_while1(|c: char| c.is_ascii_alphanumeric() || c == '$');
+
+    // Symbols may be qualified by the context they belong to, for example `Private`x`.
     map(
-        many1(one_of(
-            "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789$",
-        )),
-        |symbol_str| Expr::from(Symbol::new(&symbol_str.into_iter().collect::<String>())),
+        recognize(pair(name(), many0(pair(char('`'), name())))),
+        |symbol_str: &str| Expr::from(Symbol::new(symbol_str)),
     )
     .parse(i)
 }
 
-fn parse_infix_operator(i: &str) -> IResult<&str, (Symbol, u8)> {
+fn parse_infix_operator(i: &str) -> IResult<&str, (Symbol, u8, Associativity)> {
     let (i, _) = multispace0(i)?;
 
-    // IMPORTANT: Operators are ordered longest-first within each starting character
-    // to ensure proper matching (e.g., @@@ before @@, === before ==)
-    let (i, (op, priority)) = alt((
-        // Three-character operators (must come first)
-        alt((
-            tag("@@@").map(|_| (Symbol::new("MapApply"), 120)),
-            tag("=!=").map(|_| (Symbol::new("UnsameQ"), 20)),
-            tag("===").map(|_| (Symbol::new("SameQ"), 20)),
-            tag("//.").map(|_| (Symbol::new("ReplaceRepeated"), 13)),
-        )),
-        // Two-character operators
-        alt((
-            tag("@@").map(|_| (Symbol::new("Apply"), 120)),
-            tag("/@").map(|_| (Symbol::new("Map"), 120)),
-            tag("/.").map(|_| (Symbol::new("ReplaceAll"), 13)),
-            tag("//").map(|_| (Symbol::new("PostfixApplication"), 10)),
-            tag("<>").map(|_| (Symbol::new("StringJoin"), 90)),
-            tag("<=").map(|_| (Symbol::new("LessEqual"), 26)),
-            tag(":>").map(|_| (Symbol::new("RuleDelayed"), 14)),
-            tag(":=").map(|_| (Symbol::new("SetDelayed"), 11)),
-            tag(">=").map(|_| (Symbol::new("GreaterEqual"), 25)),
-            tag("->").map(|_| (Symbol::new("Rule"), 15)),
-            tag("==").map(|_| (Symbol::new("Equal"), 21)),
-            tag("!=").map(|_| (Symbol::new("Unequal"), 21)),
-            tag(";;").map(|_| (Symbol::new("Span"), 80)),
-            tag("&&").map(|_| (Symbol::new("And"), 4)),
-            tag("||").map(|_| (Symbol::new("Or"), 3)),
-        )),
-        // Single-character operators (must come last)
-        alt((
-            tag("<").map(|_| (Symbol::new("Less"), 26)),
-            tag(">").map(|_| (Symbol::new("Greater"), 25)),
-            tag("=").map(|_| (Symbol::new("Set"), 12)),
-            tag("+").map(|_| (Symbol::new("Plus"), 60)),
-            tag("-").map(|_| (Symbol::new("Subtract"), 50)),
-            tag("*").map(|_| (Symbol::new("Times"), 100)),
-            tag("/").map(|_| (Symbol::new("Divide"), 105)),
-            tag("^").map(|_| (Symbol::new("Power"), 101)),
-            tag(";").map(|_| (Symbol::new("CompoundExpression"), 2)),
-        )),
-    ))
-    .parse(i)?;
+    let Some((i, (_, op, priority, associativity))) = INFIX_OPERATORS
+        .iter()
+        .find_map(|operator| Some((i.strip_prefix(operator.0)?, operator)))
+    else {
+        return Err(nom::Err::Error(ParseError::from_error_kind(i, ErrorKind::Tag)));
+    };
 
     let (i, _) = multispace0(i)?;
 
-    Ok((i, (op, priority)))
+    Ok((i, (Symbol::new(op), *priority, *associativity)))
 }
 
-fn parse_part(i: &str) -> IResult<&str, Expr> {
+fn parse_part(i: &str, precision: u32) -> IResult<&str, Expr> {
     let (i, expr) = parse_symbol(i)?;
     let (i, mut exprs) = preceded(
         tag("[["),
         cut(terminated(
-            separated_list0(preceded(multispace0, char(',')), signed_expr),
+            |i| parse_elements(i, precision),
             preceded(multispace0, tag("]]")),
         )),
     )
@@ -345,28 +708,21 @@ fn parse_part(i: &str) -> IResult<&str, Expr> {
     Ok((i, Expr::from(Normal::new(Symbol::new("Part"), elems))))
 }
 
-fn parse_function(i: &str) -> IResult<&str, Expr> {
+fn parse_function(i: &str, precision: u32) -> IResult<&str, Expr> {
     let (i, expr) = alt((
         parse_slot,
-        parse_array,
-        parse_parenthesized,
-        parse_part,
-        parse_num,
+        |i| parse_array(i, precision),
+        |i| parse_parenthesized(i, precision),
+        |i| parse_part(i, precision),
+        |i| parse_num(i, precision),
         parse_pattern,
         parse_symbol,
         parse_string,
-        parse_association,
+        |i| parse_association(i, precision),
     ))
     .parse(i)?;
 
-    let (i, exprs) = many1(preceded(
-        char('['),
-        cut(terminated(
-            separated_list0(preceded(multispace0, char(',')), signed_expr),
-            preceded(multispace0, char(']')),
-        )),
-    ))
-    .parse(i)?;
+    let (i, exprs) = many1(|i| parse_arguments(i, precision)).parse(i)?;
 
     let mut new_head = expr;
     for elems in exprs {
@@ -376,26 +732,39 @@ fn parse_function(i: &str) -> IResult<&str, Expr> {
     Ok((i, new_head))
 }
 
-fn parse_association(i: &str) -> IResult<&str, Expr> {
+/// Parses an association such as `<|a -> 1, b :> 2|>`, failing unless every entry is a `Rule` or
+/// `RuleDelayed`.
+fn parse_association(i: &str, precision: u32) -> IResult<&str, Expr> {
+    let start = i;
     let (i, exprs) = preceded(
         tag("<|"),
         cut(terminated(
-            separated_list0(preceded(multispace0, char(',')), signed_expr),
+            |i| parse_elements(i, precision),
             preceded(multispace0, tag("|>")),
         )),
     )
     .parse(i)?;
 
+    let is_rule = |expr: &Expr| {
+        expr.try_normal_head(&Symbol::new("Rule"))
+            .or_else(|| expr.try_normal_head(&Symbol::new("RuleDelayed")))
+            .is_some_and(|rule| rule.len() == 2)
+    };
+
+    if !exprs.iter().all(is_rule) {
+        return Err(nom::Err::Failure(ParseError::from_error_kind(start, ErrorKind::Verify)));
+    }
+
     Ok((
         i,
         Expr::from(Normal::new(Symbol::new("Association"), exprs)),
     ))
 }
-fn parse_array(i: &str) -> IResult<&str, Expr> {
+fn parse_array(i: &str, precision: u32) -> IResult<&str, Expr> {
     let (i, exprs) = preceded(
         char('{'),
         cut(terminated(
-            separated_list0(preceded(multispace0, char(',')), signed_expr),
+            |i| parse_elements(i, precision),
             preceded(multispace0, char('}')),
         )),
     )
@@ -404,10 +773,10 @@ fn parse_array(i: &str) -> IResult<&str, Expr> {
     Ok((i, Expr::from(Normal::new(Symbol::new("List"), exprs))))
 }
 
-fn parse_parenthesized(i: &str) -> IResult<&str, Expr> {
+fn parse_parenthesized(i: &str, precision: u32) -> IResult<&str, Expr> {
     let (i, _) = char('(')(i)?;
     let (i, _) = multispace0(i)?;
-    let (i, expr) = signed_expr(i)?;
+    let (i, expr) = signed_expr(i, precision)?;
     let (i, _) = multispace0(i)?;
     let (i, _) = char(')')(i)?;
 
@@ -422,3 +791,480 @@ fn parse_single_postfix_op(i: &str) -> IResult<&str, &str> {
     ))
     .parse(i)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_str;
+
+    fn parses_as(input: &str, expected: &str) {
+        assert_eq!(parse_str(input).unwrap(), parse_str(expected).unwrap());
+    }
+
+    mod precision {
+        use crate::{
+            Context, MAX_PRECISION_DIGITS, parse_str, parse_str_with_context, real_precision,
+        };
+
+        fn precision_of(input: &str, context: &Context) -> u32 {
+            let expr = parse_str_with_context(input, context).unwrap();
+            real_precision(expr.try_real().unwrap())
+        }
+
+        #[test]
+        fn default() {
+            let expr = parse_str("1.5").unwrap();
+
+            assert_eq!(real_precision(expr.try_real().unwrap()), 53);
+        }
+
+        #[test]
+        fn from_context() {
+            let mut context = Context::new();
+            assert_eq!(precision_of("1.5", &context), 53);
+
+            context.set_default_real_precision(128);
+            assert_eq!(precision_of("1.5", &context), 128);
+        }
+
+        #[test]
+        fn nested() {
+            let mut context = Context::new();
+            context.set_default_real_precision(100);
+
+            let expr = parse_str_with_context("f[{1.5}]", &context).unwrap();
+            let list = &expr.try_normal().unwrap().elements()[0];
+            let real = &list.try_normal().unwrap().elements()[0];
+
+            assert_eq!(real_precision(real.try_real().unwrap()), 100);
+        }
+
+        #[test]
+        fn annotated_precision() {
+            assert_eq!(precision_of("3.14`20", &Context::new()), 67);
+            assert_eq!(precision_of("-3.14`30", &Context::new()), 100);
+        }
+
+        #[test]
+        fn annotated_integer() {
+            assert_eq!(precision_of("3`20", &Context::new()), 67);
+        }
+
+        #[test]
+        fn annotated_accuracy() {
+            // 10 digits of accuracy on 1.5 is 34 bits after the binary point, and 1 before it.
+            assert_eq!(precision_of("1.5``10", &Context::new()), 35);
+            assert_eq!(precision_of("1500.0``10", &Context::new()), 45);
+        }
+
+        #[test]
+        fn maximum() {
+            let max = MAX_PRECISION_DIGITS;
+
+            assert!(parse_str(&format!("1.5`{}", max)).is_ok());
+            assert!(parse_str(&format!("1.5`{}", max + 1)).is_err());
+            assert!(parse_str("1.5`4000000000").is_err());
+            assert!(parse_str(&format!("1.5``{}", max + 1)).is_err());
+            assert!(parse_str("1``10*^100000000").is_err());
+        }
+
+        #[test]
+        fn annotated_machine_precision() {
+            let mut context = Context::new();
+            context.set_default_real_precision(128);
+
+            assert_eq!(precision_of("1.5`", &context), 53);
+            assert_eq!(precision_of("1.5", &context), 128);
+        }
+
+        #[test]
+        fn annotated_value() {
+            let expr = parse_str("-2.5`30").unwrap();
+
+            assert_eq!(expr.try_real().unwrap().as_float().to_f64(), -2.5);
+        }
+    }
+
+    mod numbers {
+        use super::parses_as;
+        use crate::{BigInteger, BigRational, Expr, MAX_PRECISION_DIGITS, parse_str};
+
+        #[test]
+        fn real_exponent() {
+            parses_as("2.5*^3", "2500.");
+            parses_as("1.0*^-2", "0.01");
+            parses_as("-2.5*^+3", "-2500.");
+        }
+
+        #[test]
+        fn integer_exponent() {
+            parses_as("2*^3", "2000");
+
+            assert_eq!(
+                parse_str("2*^-3").unwrap(),
+                Expr::from(BigRational::from((2, 1000)))
+            );
+        }
+
+        #[test]
+        fn maximum_integer_exponent() {
+            let max = MAX_PRECISION_DIGITS as i64;
+
+            assert!(parse_str(&format!("1*^{}", max)).is_ok());
+            assert!(parse_str(&format!("1*^-{}", max)).is_ok());
+            assert!(parse_str(&format!("1*^{}", max + 1)).is_err());
+            assert!(parse_str(&format!("1*^-{}", max + 1)).is_err());
+            assert!(parse_str("1*^300000000").is_err());
+
+            // Reals are not expanded digit by digit, so are not limited in the same way.
+            assert!(parse_str("1.0*^300000000").is_ok());
+        }
+
+        #[test]
+        fn scientific_notation() {
+            parses_as("1e10", "10000000000.");
+            parses_as("1.5E-3", "0.0015");
+        }
+
+        #[test]
+        fn integer() {
+            assert_eq!(parse_str("42").unwrap(), Expr::from(BigInteger::from(42)));
+        }
+
+        #[test]
+        fn hexadecimal() {
+            assert_eq!(parse_str("16^^FF").unwrap(), Expr::from(BigInteger::from(255)));
+            assert_eq!(parse_str("16^^ff").unwrap(), Expr::from(BigInteger::from(255)));
+        }
+
+        #[test]
+        fn binary() {
+            assert_eq!(parse_str("2^^1010").unwrap(), Expr::from(BigInteger::from(10)));
+            assert_eq!(parse_str("-2^^1010").unwrap(), Expr::from(BigInteger::from(-10)));
+        }
+
+        #[test]
+        fn base_out_of_range() {
+            assert!(parse_str("37^^1").is_err());
+            assert!(parse_str("1^^0").is_err());
+        }
+
+        #[test]
+        fn invalid_base_digits() {
+            assert!(parse_str("2^^102").is_err());
+        }
+    }
+
+    mod unary_minus {
+        use super::parses_as;
+        use crate::{BigInteger, Expr, parse_str};
+
+        #[test]
+        fn symbol() {
+            parses_as("-a", "Times[-1, a]");
+        }
+
+        #[test]
+        fn binds_looser_than_power() {
+            parses_as("-a^2", "Times[-1, Power[a, 2]]");
+            parses_as("-2^2", "Times[-1, Power[2, 2]]");
+        }
+
+        #[test]
+        fn binds_tighter_than_plus() {
+            parses_as("-a*b", "Times[Times[-1, a], b]");
+            parses_as("-a + b", "Plus[Times[-1, a], b]");
+        }
+
+        #[test]
+        fn after_infix_operator() {
+            parses_as("3 - -a", "Subtract[3, Times[-1, a]]");
+            parses_as("a^-b", "Power[a, Times[-1, b]]");
+        }
+
+        #[test]
+        fn number_literal() {
+            assert_eq!(parse_str("-1").unwrap(), Expr::from(BigInteger::from(-1)));
+            assert_eq!(parse_str("-16^^FF").unwrap(), Expr::from(BigInteger::from(-255)));
+        }
+    }
+
+    mod additive {
+        use super::parses_as;
+
+        #[test]
+        fn subtract_then_plus() {
+            parses_as("a - b + c", "Plus[Subtract[a, b], c]");
+        }
+
+        #[test]
+        fn plus_then_subtract() {
+            parses_as("a + b - c", "Subtract[Plus[a, b], c]");
+        }
+
+        #[test]
+        fn repeated_subtract() {
+            parses_as("a - b - c", "Subtract[Subtract[a, b], c]");
+        }
+
+        #[test]
+        fn mixed_with_times() {
+            parses_as("a - b * c + d", "Plus[Subtract[a, Times[b, c]], d]");
+        }
+    }
+
+    mod postfix {
+        use super::parses_as;
+        use crate::parse_str;
+
+        #[test]
+        fn four_underscores() {
+            assert!(parse_str("x____").is_err());
+            assert!(parse_str("f[x____]").is_err());
+        }
+
+        #[test]
+        fn three_underscores() {
+            parses_as("x___", "Pattern[x, BlankNullSequence[]]");
+        }
+
+        #[test]
+        fn derivative() {
+            parses_as("f'", "Derivative[1][f]");
+        }
+
+        #[test]
+        fn derivative_chain() {
+            parses_as("f''", "Derivative[1][Derivative[1][f]]");
+        }
+
+        #[test]
+        fn factorial_chain() {
+            parses_as("5!!!", "Factorial[Factorial2[5]]");
+        }
+    }
+
+    mod associativity {
+        use super::parses_as;
+
+        #[test]
+        fn right_associative_power() {
+            parses_as("2^3^2", "Power[2, Power[3, 2]]");
+        }
+
+        #[test]
+        fn left_associative_divide() {
+            parses_as("a/b/c", "Divide[Divide[a, b], c]");
+        }
+
+        #[test]
+        fn power_binds_tighter_than_divide() {
+            parses_as("a/b^2", "Divide[a, Power[b, 2]]");
+        }
+
+        #[test]
+        fn right_associative_rules() {
+            parses_as("a -> b -> c", "Rule[a, Rule[b, c]]");
+            parses_as("a -> b :> c", "Rule[a, RuleDelayed[b, c]]");
+        }
+
+        #[test]
+        fn right_associative_assignment() {
+            parses_as("a = b = c", "Set[a, Set[b, c]]");
+            parses_as("a = b := c", "Set[a, SetDelayed[b, c]]");
+        }
+
+        #[test]
+        fn tag_assignment() {
+            parses_as("g /: f[g] = 1", "TagSet[g, f[g], 1]");
+            parses_as("g /: f[g[x_]] := x", "TagSetDelayed[g, f[g[Pattern[x, Blank[]]]], x]");
+            parses_as("g /: f[g] := x; y", "CompoundExpression[TagSetDelayed[g, f[g], x], y]");
+        }
+    }
+
+    mod elements {
+        use super::parses_as;
+        use crate::{Expr, Normal, Symbol, parse_str};
+
+        #[test]
+        fn empty() {
+            assert_eq!(
+                parse_str("{}").unwrap(),
+                Expr::from(Normal::new(Symbol::new("List"), vec![]))
+            );
+            assert_eq!(
+                parse_str("f[]").unwrap(),
+                Expr::from(Normal::new(Symbol::new("f"), vec![]))
+            );
+            assert_eq!(
+                parse_str("<||>").unwrap(),
+                Expr::from(Normal::new(Symbol::new("Association"), vec![]))
+            );
+        }
+
+        #[test]
+        fn trailing_comma() {
+            parses_as("{a, b,}", "{a, b}");
+            parses_as("f[a, b ,]", "f[a, b]");
+            parses_as("f[a,][b,]", "f[a][b]");
+            parses_as("<|a -> 1, b -> 2,|>", "<|a -> 1, b -> 2|>");
+            parses_as("x[[1, 2,]]", "x[[1, 2]]");
+        }
+
+        #[test]
+        fn lone_comma() {
+            for input in ["{,}", "f[,]", "<|,|>", "{a,,}", "f[a,,b]"] {
+                assert!(parse_str(input).is_err(), "{:?} should not parse", input);
+            }
+        }
+    }
+
+    mod associations {
+        use super::parses_as;
+        use crate::{BigInteger, Expr, Normal, Symbol, parse_str};
+
+        #[test]
+        fn rules() {
+            let rule = |head: &str, key: &str, value: i64| {
+                Expr::from(Normal::new(
+                    Symbol::new(head),
+                    vec![Expr::from(Symbol::new(key)), Expr::from(BigInteger::from(value))],
+                ))
+            };
+
+            assert_eq!(
+                parse_str("<|a -> 1, b :> 2|>").unwrap(),
+                Expr::from(Normal::new(
+                    Symbol::new("Association"),
+                    vec![rule("Rule", "a", 1), rule("RuleDelayed", "b", 2)]
+                ))
+            );
+            parses_as("<|a -> <|b -> 1|>|>", "Association[Rule[a, Association[Rule[b, 1]]]]");
+        }
+
+        #[test]
+        fn entries_must_be_rules() {
+            for input in ["<|a, b|>", "<|a -> 1, b|>", "<|{a -> 1}|>", "f[<|1|>]"] {
+                assert!(parse_str(input).is_err(), "{:?} should not parse", input);
+            }
+        }
+    }
+
+    mod symbols {
+        use crate::{Expr, Normal, Symbol, parse_str};
+
+        #[test]
+        fn qualified_by_context() {
+            assert_eq!(
+                parse_str("Private`x").unwrap(),
+                Expr::from(Symbol::new("Private`x"))
+            );
+            assert_eq!(
+                parse_str("A`B`f[x]").unwrap(),
+                Expr::from(Normal::new(Symbol::new("A`B`f"), vec![Expr::from(Symbol::new("x"))]))
+            );
+        }
+
+        #[test]
+        fn precision_is_not_a_context() {
+            assert!(parse_str("1.5`10").unwrap().try_real().is_some());
+        }
+    }
+
+    mod strings {
+        use crate::{Expr, parse_str};
+
+        fn parses_as_string(input: &str, expected: &str) {
+            assert_eq!(parse_str(input).unwrap(), Expr::from(expected.to_owned()));
+        }
+
+        #[test]
+        fn quote_and_backslash() {
+            parses_as_string(r#""a\"b""#, "a\"b");
+            parses_as_string(r#""a\\b""#, "a\\b");
+        }
+
+        #[test]
+        fn whitespace_escapes() {
+            parses_as_string(r#""a\nb""#, "a\nb");
+            parses_as_string(r#""a\tb\r""#, "a\tb\r");
+        }
+
+        #[test]
+        fn unicode_escape() {
+            parses_as_string(r#""\:00e9""#, "\u{e9}");
+            parses_as_string(r#""caf\:00E9!""#, "caf\u{e9}!");
+        }
+
+        #[test]
+        fn round_trip() {
+            for value in ["say \"hi\"", "a\\b", "line\nbreak\ttab", "bell\u{7}"] {
+                let expr = Expr::from(value.to_owned());
+
+                assert_eq!(parse_str(&expr.to_string()).unwrap(), expr);
+            }
+        }
+
+        #[test]
+        fn invalid_escape() {
+            assert!(parse_str(r#""a\qb""#).is_err());
+            assert!(parse_str(r#""\:00""#).is_err());
+        }
+    }
+
+    mod malformed {
+        use super::super::MAX_NESTING_DEPTH;
+        use crate::{BigInteger, Expr, Normal, Symbol, parse_str};
+
+        #[test]
+        fn corpus() {
+            let corpus = [
+                "", " ", "(", ")", "f[", "f[a", "f[a,", "]", "{a,", "{a", "}", "<|a", "|>", "\"abc",
+                "\"\\q\"", "a +", "* a", "a[[1", "a[[1]", "x____", "16^^GG", "99^^1", "1.5`x",
+                "f[a)", "(a]", "a /:", "#a#", "&&&",
+            ];
+
+            for input in corpus {
+                assert!(parse_str(input).is_err(), "{:?} should not parse", input);
+            }
+        }
+
+        #[test]
+        fn nesting_limit() {
+            let nested = |depth: usize| format!("{}a{}", "(".repeat(depth), ")".repeat(depth));
+
+            assert!(parse_str(&nested(MAX_NESTING_DEPTH - 1)).is_ok());
+            assert!(parse_str(&nested(10_000)).is_err());
+            assert!(parse_str(&format!("{}a{}", "f[".repeat(10_000), "]".repeat(10_000))).is_err());
+            assert!(parse_str(&format!("{}a", "-".repeat(10_000))).is_err());
+        }
+
+        #[test]
+        fn large_slot() {
+            let expected = Expr::from(Normal::new(
+                Symbol::new("Slot"),
+                vec![Expr::from(
+                    BigInteger::from_str_radix("99999999999999999999", 10).unwrap(),
+                )],
+            ));
+
+            assert_eq!(parse_str("#99999999999999999999").unwrap(), expected);
+        }
+
+        #[test]
+        fn nesting_limit_error() {
+            let error = parse_str(&format!("{}a", "{".repeat(100))).unwrap_err();
+
+            assert!(error.contains(&format!("at most {} levels deep", MAX_NESTING_DEPTH)));
+            assert!(!parse_str("{a").unwrap_err().contains("levels deep"));
+        }
+
+        #[test]
+        fn large_exponent() {
+            // The exponents are combined without overflowing, giving a real too large to represent.
+            let expr = parse_str("1e9223372036854775807*^5").unwrap();
+            assert!(expr.try_real().unwrap().as_float().is_infinite());
+
+            assert!(parse_str("1*^9223372036854775807").is_err());
+        }
+    }
+}
@@ -0,0 +1,40 @@
+/// Declares a test which parses and fully evaluates `$input` within a global context, asserting
+/// that the result is equal to the parsed form of `$expected`.
+///
+/// Optionally a list of `$setup` inputs can be given, which are evaluated in order beforehand.
+macro_rules! evaluate_test {
+    ($name:ident, $input:expr, $expected:expr) => {
+        evaluate_test!($name, [], $input, $expected);
+    };
+
+    ($name:ident, [$($setup:expr),* $(,)?], $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() -> () {
+            let mut context = crate::Context::new_global_context();
+
+            $(
+                crate::evaluate(crate::parse_str($setup).unwrap(), &mut context).unwrap();
+            )*
+
+            let result = crate::evaluate(crate::parse_str($input).unwrap(), &mut context).unwrap();
+
+            assert_eq!(result, crate::parse_str($expected).unwrap());
+        }
+    };
+}
+
+/// Declares a test which parses `$input` and performs a single evaluation step within a global
+/// context, asserting that the result is equal to the parsed form of `$expected`.
+macro_rules! evaluate_step_test {
+    ($name:ident, $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() -> () {
+            let mut context = crate::Context::new_global_context();
+
+            let result =
+                crate::evaluate_step(crate::parse_str($input).unwrap(), &mut context).unwrap();
+
+            assert_eq!(result.into_expr(), crate::parse_str($expected).unwrap());
+        }
+    };
+}